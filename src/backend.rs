@@ -0,0 +1,370 @@
+//! Pluggable git backends.
+//!
+//! Workspace operations (`status`, `rebase`, `commit-all`, `push-all`)
+//! traditionally shell out to the `git` binary. [`GitBackend`] abstracts that
+//! so we can also run against a pure-Rust implementation (built on
+//! `gitoxide`) in environments where no `git` executable is installed, and so
+//! unit tests can exercise command logic against [`mock::MockBackend`]
+//! instead of a real repository.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Status of a single repository as reported by a backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoStatus {
+    pub branch: String,
+    pub dirty: bool,
+    pub ahead: u32,
+    pub behind: u32,
+}
+
+/// Operations a git backend must support for workspace commands.
+pub trait GitBackend {
+    /// Compute status (branch, dirty/ahead/behind) for the repo at `path`.
+    fn status(&self, path: &Path) -> Result<RepoStatus>;
+    /// Fetch the latest changes, fast-forward or rebase onto the detected
+    /// default branch, and surface a conflict as an error rather than
+    /// leaving the repo mid-rebase silently.
+    fn rebase(&self, path: &Path) -> Result<()>;
+    /// Stage and commit all changes in the repo at `path`.
+    fn commit_all(&self, path: &Path, message: &str) -> Result<()>;
+    /// Push the current branch for the repo at `path`.
+    fn push_all(&self, path: &Path) -> Result<()>;
+    /// Name of the currently checked-out branch at `path`.
+    fn get_current_branch(&self, path: &Path) -> Result<String>;
+    /// Whether `path` has uncommitted changes (tracked or staged).
+    fn has_uncommitted_changes(&self, path: &Path) -> Result<bool>;
+    /// Whether `path`'s current branch has commits not yet on its upstream.
+    fn has_unpushed_commits(&self, path: &Path) -> Result<bool>;
+    /// Number of stash entries at `path`.
+    fn get_stash_count(&self, path: &Path) -> Result<usize>;
+}
+
+/// Backend that shells out to the system `git` (via [`crate::git::create_command`]).
+#[derive(Debug, Default)]
+pub struct ShellBackend;
+
+impl GitBackend for ShellBackend {
+    fn status(&self, path: &Path) -> Result<RepoStatus> {
+        let branch = crate::git::get_current_branch(path)?;
+        let dirty = crate::git::has_uncommitted_changes(path)?;
+        let ahead = crate::git::run_git_command_string(
+            &["rev-list", "--count", "@{u}..HEAD"],
+            Some(path),
+        )
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+        let behind = crate::git::run_git_command_string(
+            &["rev-list", "--count", "HEAD..@{u}"],
+            Some(path),
+        )
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+
+        Ok(RepoStatus {
+            branch,
+            dirty,
+            ahead,
+            behind,
+        })
+    }
+
+    fn rebase(&self, path: &Path) -> Result<()> {
+        if crate::git::has_uncommitted_changes(path)? {
+            anyhow::bail!(
+                "Cannot rebase with uncommitted changes. Please commit or stash your changes first."
+            );
+        }
+
+        crate::git::fetch(path)?;
+
+        let current_branch = crate::git::get_current_branch(path)?;
+        let rebase_target = crate::git::get_default_branch(path)
+            .with_context(|| "Failed to detect default branch for repository")?;
+        let target_branch_name = rebase_target.strip_prefix("origin/").unwrap_or(&rebase_target);
+
+        if current_branch == target_branch_name {
+            return crate::git::merge_fast_forward(&rebase_target, path);
+        }
+
+        match crate::git::rebase(&rebase_target, path) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                if path.join(".git/rebase-merge").exists() || path.join(".git/rebase-apply").exists() {
+                    crate::ui::print_error("Rebase conflict detected!");
+                    crate::ui::print_info("Manual resolution required:");
+                    crate::ui::print_info("   1. Navigate to the repository:");
+                    crate::ui::print_info(&format!("      cd {}", path.display()));
+                    crate::ui::print_info("   2. Resolve conflicts in the affected files");
+                    crate::ui::print_info("   3. Stage resolved files: git add <file>");
+                    crate::ui::print_info("   4. Continue rebase: git rebase --continue");
+                    crate::ui::print_info("   5. Or abort rebase: git rebase --abort");
+                    crate::ui::print_info("");
+                    crate::ui::print_info("Common conflict resolution:");
+                    crate::ui::print_info("   • Edit files to resolve <<<< ==== >>>> markers");
+                    crate::ui::print_info("   • Use 'git status' to see conflicted files");
+                    crate::ui::print_info("   • Use 'git diff' to see conflict details");
+                    anyhow::bail!("Rebase conflict requires manual resolution")
+                }
+                Err(e).context("Rebase failed")
+            }
+        }
+    }
+
+    fn commit_all(&self, path: &Path, message: &str) -> Result<()> {
+        crate::git::add_all(path)?;
+        crate::git::commit(message, path)
+    }
+
+    fn push_all(&self, path: &Path) -> Result<()> {
+        crate::git::push(path)
+    }
+
+    fn get_current_branch(&self, path: &Path) -> Result<String> {
+        crate::git::get_current_branch(path)
+    }
+
+    fn has_uncommitted_changes(&self, path: &Path) -> Result<bool> {
+        crate::git::has_uncommitted_changes(path)
+    }
+
+    fn has_unpushed_commits(&self, path: &Path) -> Result<bool> {
+        crate::git::has_unpushed_commits(path)
+    }
+
+    fn get_stash_count(&self, path: &Path) -> Result<usize> {
+        crate::git::get_stash_count(path)
+    }
+}
+
+/// Backend built on `gitoxide` (`gix`): reads the index and ref graph directly
+/// instead of parsing `git` stdout, and needs no `git` binary on `PATH`.
+#[derive(Debug, Default)]
+pub struct GixBackend;
+
+impl GitBackend for GixBackend {
+    fn status(&self, path: &Path) -> Result<RepoStatus> {
+        let repo = gix::open(path)
+            .with_context(|| format!("Failed to open repository at {}", path.display()))?;
+
+        let head = repo.head()?;
+        let branch = head
+            .referent_name()
+            .map(|n| n.shorten().to_string())
+            .unwrap_or_else(|| "HEAD".to_string());
+
+        let dirty = repo
+            .status(gix::progress::Discard)?
+            .into_iter(None)?
+            .next()
+            .is_some();
+
+        // Ahead/behind against the upstream tracking ref, when configured.
+        let (ahead, behind) = repo
+            .head_id()
+            .ok()
+            .and_then(|head_id| {
+                let upstream = repo
+                    .branch_remote_tracking_ref_name(head.referent_name()?, gix::remote::Direction::Fetch)?
+                    .ok()?;
+                let upstream_id = repo.find_reference(&upstream).ok()?.id();
+                repo.graph_ahead_behind(head_id.detach(), upstream_id.detach()).ok()
+            })
+            .unwrap_or((0, 0));
+
+        Ok(RepoStatus {
+            branch,
+            dirty,
+            ahead: ahead as u32,
+            behind: behind as u32,
+        })
+    }
+
+    fn rebase(&self, path: &Path) -> Result<()> {
+        // Rebasing through the object database directly is not yet wired up;
+        // fall back to the shell backend rather than silently no-op.
+        ShellBackend.rebase(path)
+    }
+
+    fn commit_all(&self, path: &Path, message: &str) -> Result<()> {
+        // Staging/committing through the object database directly is not yet
+        // wired up; fall back to the shell backend rather than silently no-op.
+        ShellBackend.commit_all(path, message)
+    }
+
+    fn push_all(&self, path: &Path) -> Result<()> {
+        ShellBackend.push_all(path)
+    }
+
+    fn get_current_branch(&self, path: &Path) -> Result<String> {
+        Ok(self.status(path)?.branch)
+    }
+
+    fn has_uncommitted_changes(&self, path: &Path) -> Result<bool> {
+        Ok(self.status(path)?.dirty)
+    }
+
+    fn has_unpushed_commits(&self, path: &Path) -> Result<bool> {
+        Ok(self.status(path)?.ahead > 0)
+    }
+
+    fn get_stash_count(&self, path: &Path) -> Result<usize> {
+        // Stash listing isn't implemented against the object database yet;
+        // fall back to the shell backend rather than silently no-op.
+        ShellBackend.get_stash_count(path)
+    }
+}
+
+/// Which backend to use for git operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackendKind {
+    /// Shell out to the system `git` binary (current default behavior).
+    #[default]
+    Shell,
+    /// Use the pure-Rust `gitoxide` backend; no `git` binary required.
+    Gitoxide,
+    /// Use the shell backend if `git` is on `PATH`, otherwise `gitoxide`.
+    Auto,
+}
+
+impl BackendKind {
+    /// Parse a backend kind from a config/CLI value (`"shell"`, `"gitoxide"`, `"auto"`).
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "shell" => Ok(Self::Shell),
+            "gitoxide" | "gix" => Ok(Self::Gitoxide),
+            "auto" => Ok(Self::Auto),
+            other => anyhow::bail!("Unknown git backend '{other}' (expected shell, gitoxide, or auto)"),
+        }
+    }
+
+    /// Resolve `self` to a concrete backend, auto-detecting when needed.
+    /// `Send + Sync` so workspace commands can share one backend across the
+    /// [`crate::parallel::parallel_map`] worker pool.
+    #[must_use]
+    pub fn resolve(self) -> Box<dyn GitBackend + Send + Sync> {
+        match self {
+            Self::Shell => Box::new(ShellBackend),
+            Self::Gitoxide => Box::new(GixBackend),
+            Self::Auto => {
+                if crate::git::check_git_availability().is_ok() {
+                    Box::new(ShellBackend)
+                } else {
+                    Box::new(GixBackend)
+                }
+            }
+        }
+    }
+}
+
+/// Hand-rolled mockall-style [`GitBackend`] for unit tests: each method
+/// returns a canned response configured via the `with_*` builders, erroring
+/// if the test didn't configure one, so a missing expectation fails loudly
+/// rather than silently falling through to a real subprocess. Mirrors
+/// [`crate::git::mock::MockGitBackend`]'s shape for the workspace-level trait.
+#[cfg(test)]
+pub(crate) mod mock {
+    use super::{GitBackend, RepoStatus, Result};
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    #[derive(Default)]
+    pub struct MockBackend {
+        statuses: RefCell<HashMap<PathBuf, RepoStatus>>,
+        stash_counts: RefCell<HashMap<PathBuf, usize>>,
+        rebase_results: RefCell<HashMap<PathBuf, Result<(), String>>>,
+        push_results: RefCell<HashMap<PathBuf, Result<(), String>>>,
+        pub committed: RefCell<Vec<(PathBuf, String)>>,
+        pub pushed: RefCell<Vec<PathBuf>>,
+        pub rebased: RefCell<Vec<PathBuf>>,
+    }
+
+    impl MockBackend {
+        #[must_use]
+        pub fn with_status(self, path: &Path, status: RepoStatus) -> Self {
+            self.statuses.borrow_mut().insert(path.to_path_buf(), status);
+            self
+        }
+
+        #[must_use]
+        pub fn with_stash_count(self, path: &Path, count: usize) -> Self {
+            self.stash_counts.borrow_mut().insert(path.to_path_buf(), count);
+            self
+        }
+
+        #[must_use]
+        pub fn with_rebase_failure(self, path: &Path, error: &str) -> Self {
+            self.rebase_results
+                .borrow_mut()
+                .insert(path.to_path_buf(), Err(error.to_string()));
+            self
+        }
+
+        #[must_use]
+        pub fn with_push_failure(self, path: &Path, error: &str) -> Self {
+            self.push_results
+                .borrow_mut()
+                .insert(path.to_path_buf(), Err(error.to_string()));
+            self
+        }
+
+        fn status_or_error(&self, path: &Path) -> Result<RepoStatus> {
+            self.statuses
+                .borrow()
+                .get(path)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("mock: no status configured for '{}'", path.display()))
+        }
+    }
+
+    impl GitBackend for MockBackend {
+        fn status(&self, path: &Path) -> Result<RepoStatus> {
+            self.status_or_error(path)
+        }
+
+        fn rebase(&self, path: &Path) -> Result<()> {
+            self.rebased.borrow_mut().push(path.to_path_buf());
+            match self.rebase_results.borrow().get(path) {
+                Some(Err(e)) => anyhow::bail!("{e}"),
+                _ => Ok(()),
+            }
+        }
+
+        fn commit_all(&self, path: &Path, message: &str) -> Result<()> {
+            self.committed
+                .borrow_mut()
+                .push((path.to_path_buf(), message.to_string()));
+            Ok(())
+        }
+
+        fn push_all(&self, path: &Path) -> Result<()> {
+            self.pushed.borrow_mut().push(path.to_path_buf());
+            match self.push_results.borrow().get(path) {
+                Some(Err(e)) => anyhow::bail!("{e}"),
+                _ => Ok(()),
+            }
+        }
+
+        fn get_current_branch(&self, path: &Path) -> Result<String> {
+            Ok(self.status_or_error(path)?.branch)
+        }
+
+        fn has_uncommitted_changes(&self, path: &Path) -> Result<bool> {
+            Ok(self.status_or_error(path)?.dirty)
+        }
+
+        fn has_unpushed_commits(&self, path: &Path) -> Result<bool> {
+            Ok(self.status_or_error(path)?.ahead > 0)
+        }
+
+        fn get_stash_count(&self, path: &Path) -> Result<usize> {
+            self.stash_counts.borrow().get(path).copied().ok_or_else(|| {
+                anyhow::anyhow!("mock: no stash count configured for '{}'", path.display())
+            })
+        }
+    }
+}
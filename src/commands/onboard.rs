@@ -1,23 +1,213 @@
-use anyhow::Result;
+use std::io::{self, Write};
 
+use anyhow::{Context, Result};
+
+use crate::config;
+use crate::git::{self, SigningFormat};
+use crate::models::{Repository, Viewset, ViewsetsConfig};
 use crate::ui;
 
 pub fn handle_command() -> Result<()> {
     ui::print_header("Welcome to Viewyard!");
     ui::print_info("This will help you get set up quickly.");
-    
-    // TODO: Implement full onboarding flow
-    // 1. Check prerequisites
-    // 2. Get user info
-    // 3. Set up git config
-    // 4. Create viewsets config
-    // 5. Test setup
-    
+    println!();
+
+    check_prerequisites()?;
+    println!();
+
+    report_signing_setup();
+    println!();
+
+    let viewset_name = prompt_viewset_name()?;
+    let repos = collect_repositories()?;
+
+    // Merge into whatever's already configured rather than replacing it
+    // wholesale - a user who re-runs onboarding (or already has viewsets
+    // from editing the config directly) shouldn't lose everything else.
+    let mut viewsets_config = config::load_viewsets_config()
+        .context("Failed to load existing viewsets configuration")?;
+    viewsets_config.viewsets.insert(
+        viewset_name.clone(),
+        Viewset {
+            repos,
+            ..Default::default()
+        },
+    );
+
+    config::save_viewsets_config(&viewsets_config)
+        .context("Failed to write viewsets configuration")?;
+    ui::print_success(&format!(
+        "✓ Wrote viewsets configuration to {}",
+        config::viewsets_config_path()?.display()
+    ));
+
+    verify_round_trip(&viewsets_config)?;
+
+    println!();
     ui::print_success("Onboarding completed successfully!");
     ui::print_info("Next steps:");
-    ui::print_info("1. Create your first view: viewyard view create <task-name>");
+    ui::print_info(&format!(
+        "1. Create your first view: viewyard view create <task-name> --viewset {viewset_name}"
+    ));
     ui::print_info("2. Add more repositories by editing ~/.config/viewyard/viewsets.yaml");
     ui::print_info("3. Check the README for more examples and usage");
-    
+
     Ok(())
 }
+
+/// Confirm `git` is installed and report its version. Read-only: never
+/// touches git config, global or otherwise.
+fn check_prerequisites() -> Result<()> {
+    ui::print_info("Checking prerequisites...");
+    git::check_git_availability().context("Prerequisite check failed")?;
+
+    let version_output = git::create_command("git")
+        .args(["--version"])
+        .output()
+        .context("Failed to read git version")?;
+    let version = String::from_utf8_lossy(&version_output.stdout);
+    ui::print_success(&format!("✓ {}", version.trim()));
+
+    Ok(())
+}
+
+/// Detect an existing commit-signing key and report which format (SSH vs
+/// OpenPGP) it would sign with. Purely informational - onboarding never
+/// mutates global git config, so there's nothing to "enable" here beyond
+/// telling the user what their existing config already does.
+fn report_signing_setup() {
+    ui::print_info("Checking commit signing...");
+    match git::detect_signing_config() {
+        Some(signing) => {
+            let format_label = match signing.format {
+                SigningFormat::Openpgp => "OpenPGP",
+                SigningFormat::Ssh => "SSH",
+            };
+            ui::print_success(&format!(
+                "✓ Found an existing {format_label} signing key: {}",
+                signing.key
+            ));
+            ui::print_info(&format!(
+                "Commits in your views will be signed using this {format_label} key."
+            ));
+        }
+        None => {
+            ui::print_warning("No commit signing key configured (user.signingkey is unset).");
+            ui::print_info(
+                "To sign commits, set one up per-repository with 'git config user.signingkey <key>' \
+                 and 'git config gpg.format ssh|openpgp' - viewyard never writes global git config.",
+            );
+        }
+    }
+}
+
+fn prompt_viewset_name() -> Result<String> {
+    loop {
+        let input = prompt("Name for your first viewset [default]: ")?;
+        let name = if input.is_empty() {
+            "default".to_string()
+        } else {
+            input
+        };
+
+        if name.contains('/') || name.contains(char::is_whitespace) {
+            ui::print_warning("Viewset names can't contain '/' or whitespace");
+            continue;
+        }
+
+        return Ok(name);
+    }
+}
+
+/// Interactively collect repositories by remote URL. Each URL is parsed with
+/// [`git::parse_remote`] to prefill the account/forge instead of asking the
+/// user to type that out separately.
+fn collect_repositories() -> Result<Vec<Repository>> {
+    ui::print_info("Add repositories by remote URL (one per line, blank line to finish):");
+
+    let mut repos = Vec::new();
+    loop {
+        let url = prompt(&format!(
+            "Remote URL ({} added, blank to finish): ",
+            repos.len()
+        ))?;
+        if url.is_empty() {
+            break;
+        }
+
+        match git::parse_remote(&url) {
+            Ok(remote_info) => {
+                let repo = Repository {
+                    name: remote_info.repo.clone(),
+                    url: url.clone(),
+                    is_private: false,
+                    source: format!(
+                        "{}{})",
+                        remote_info.forge.source_prefix(),
+                        remote_info.owner
+                    ),
+                    account: Some(remote_info.owner),
+                    category: None,
+                    backend: None,
+                    topics: Vec::new(),
+                    language: None,
+                    clone_strategy: None,
+                };
+                ui::print_success(&format!("✓ Added {} ({})", repo.name, repo.source));
+                repos.push(repo);
+            }
+            Err(e) => {
+                ui::print_warning(&format!("Couldn't parse '{url}' as a git remote: {e}"));
+            }
+        }
+    }
+
+    if repos.is_empty() {
+        ui::print_warning(
+            "No repositories added - you can add them later by editing the config.",
+        );
+    }
+
+    Ok(repos)
+}
+
+/// Prove the config we just wrote actually round-trips through the loader,
+/// rather than just trusting that `save_viewsets_config` succeeded.
+fn verify_round_trip(expected: &ViewsetsConfig) -> Result<()> {
+    let reloaded = config::load_viewsets_config()
+        .context("Wrote viewsets config but failed to load it back")?;
+
+    if reloaded.viewsets.len() != expected.viewsets.len() {
+        anyhow::bail!(
+            "Viewsets config round-trip mismatch: wrote {} viewset(s), loaded {} back",
+            expected.viewsets.len(),
+            reloaded.viewsets.len()
+        );
+    }
+
+    for (name, viewset) in &expected.viewsets {
+        let reloaded_viewset = reloaded
+            .viewsets
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Viewset '{name}' missing after round-trip"))?;
+        if reloaded_viewset.repos.len() != viewset.repos.len() {
+            anyhow::bail!(
+                "Viewset '{name}' round-trip mismatch: wrote {} repo(s), loaded {} back",
+                viewset.repos.len(),
+                reloaded_viewset.repos.len()
+            );
+        }
+    }
+
+    ui::print_success("✓ Verified configuration round-trips through the loader");
+    Ok(())
+}
+
+fn prompt(label: &str) -> Result<String> {
+    print!("{label}");
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
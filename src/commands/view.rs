@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Subcommand;
 
 use crate::config;
@@ -20,6 +20,9 @@ pub enum ViewCommand {
         /// Template to use for repository selection
         #[arg(long)]
         template: Option<String>,
+        /// Select every repo carrying this tag instead of prompting or using a template
+        #[arg(long, conflicts_with = "template")]
+        tag: Option<String>,
     },
     /// Delete a view
     Delete {
@@ -34,9 +37,47 @@ pub enum ViewCommand {
         /// Viewset to list views from (optional, lists all if not specified)
         #[arg(long)]
         viewset: Option<String>,
+        /// Only show views that have a repo carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
     },
     /// Validate viewsets configuration
     Validate,
+    /// Check that a view's repos keep main/next/dev in trunk order
+    ValidateBranches {
+        /// Name of the view to check
+        name: String,
+    },
+    /// Show a per-submodule working-state breakdown for views
+    Status {
+        /// Viewset to show status for (optional, shows all if not specified)
+        #[arg(long)]
+        viewset: Option<String>,
+    },
+    /// Spawn a shell inside a view
+    Enter {
+        /// Name of the view to enter
+        name: String,
+    },
+    /// Clone missing repos and fast-forward existing ones in a view
+    Update {
+        /// Name of the view to update (omit with --all to update every view)
+        name: Option<String>,
+        /// Update every view in every viewset
+        #[arg(long, conflicts_with = "name")]
+        all: bool,
+    },
+    /// Add or remove tags on a viewset or a repo within one
+    Tag {
+        /// What to tag: a viewset name, or "<viewset>/<repo>" for a single repo
+        target: String,
+        /// Tags to add
+        #[arg(long = "add")]
+        add: Vec<String>,
+        /// Tags to remove
+        #[arg(long = "remove")]
+        remove: Vec<String>,
+    },
 }
 
 pub fn handle_command(command: ViewCommand) -> Result<()> {
@@ -45,14 +86,29 @@ pub fn handle_command(command: ViewCommand) -> Result<()> {
             name,
             viewset,
             template,
-        } => create_view(&name, viewset.as_deref(), template.as_deref()),
+            tag,
+        } => create_view(&name, viewset.as_deref(), template.as_deref(), tag.as_deref()),
         ViewCommand::Delete { name, force } => delete_view(&name, force),
-        ViewCommand::List { viewset } => list_views(viewset.as_deref()),
+        ViewCommand::List { viewset, tag } => list_views(viewset.as_deref(), tag.as_deref()),
         ViewCommand::Validate => validate_config(),
+        ViewCommand::ValidateBranches { name } => validate_view_branches(&name),
+        ViewCommand::Status { viewset } => view_status(viewset.as_deref()),
+        ViewCommand::Enter { name } => enter_view(&name),
+        ViewCommand::Update { name, all } => update_view(name.as_deref(), all),
+        ViewCommand::Tag {
+            target,
+            add,
+            remove,
+        } => tag_target(&target, &add, &remove),
     }
 }
 
-fn create_view(name: &str, viewset: Option<&str>, template: Option<&str>) -> Result<()> {
+fn create_view(
+    name: &str,
+    viewset: Option<&str>,
+    template: Option<&str>,
+    tag: Option<&str>,
+) -> Result<()> {
     // Validate view name
     if name.trim().is_empty() {
         ui::show_error_with_help(
@@ -199,8 +255,42 @@ fn create_view(name: &str, viewset: Option<&str>, template: Option<&str>) -> Res
         return Err(anyhow::anyhow!("View already exists"));
     }
 
-    // Repository selection - either from template or interactive
-    let selected_repos: Vec<&crate::models::Repository> = if let Some(template_name) = template {
+    // Repository selection - by tag, from a template, or interactive
+    let selected_repos: Vec<&crate::models::Repository> = if let Some(tag_name) = tag {
+        let selected: Vec<&Repository> = viewset_config
+            .repos
+            .iter()
+            .filter(|r| {
+                viewset_config
+                    .repo_tags
+                    .get(&r.name)
+                    .map(|tags| tags.iter().any(|t| t == tag_name))
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        if selected.is_empty() {
+            ui::show_error_with_help(
+                &format!(
+                    "No repositories in viewset '{}' carry tag '{}'",
+                    viewset_name, tag_name
+                ),
+                &[&format!(
+                    "Tag a repo first: viewyard view tag {}/<repo> --add {}",
+                    viewset_name, tag_name
+                )],
+            );
+            return Err(anyhow::anyhow!("No repositories found for tag"));
+        }
+
+        ui::print_info(&format!(
+            "Using tag '{}' - selected {} repositories",
+            tag_name,
+            selected.len()
+        ));
+
+        selected
+    } else if let Some(template_name) = template {
         // Use template for repository selection
         use crate::models::ViewTemplate;
 
@@ -279,25 +369,35 @@ fn create_view(name: &str, viewset: Option<&str>, template: Option<&str>) -> Res
             .collect();
 
         // For testing, let's just select the first repository if the view name starts with "test-"
-        let selected_indices = if name.starts_with("test-") {
+        if name.starts_with("test-") {
             ui::print_info("Test mode: automatically selecting first repository");
-            vec![0]
+            vec![&viewset_config.repos[0]]
+        } else if crate::picker::is_interactive_terminal() {
+            let picked = crate::picker::pick_repositories(&viewset_config.repos)?;
+            if picked.is_empty() {
+                anyhow::bail!("No repositories selected. View creation cancelled.");
+            }
+            picked
         } else {
-            ui::select_from_list(&repo_names, "Available repositories:", true)?
-        };
-
-        if selected_indices.is_empty() {
-            anyhow::bail!("No repositories selected. View creation cancelled.");
+            let selected_indices =
+                ui::select_from_list(&repo_names, "Available repositories:", true)?;
+            if selected_indices.is_empty() {
+                anyhow::bail!("No repositories selected. View creation cancelled.");
+            }
+            selected_indices
+                .iter()
+                .map(|&i| &viewset_config.repos[i])
+                .collect()
         }
-
-        selected_indices
-            .iter()
-            .map(|&i| &viewset_config.repos[i])
-            .collect()
     };
 
     // Create view directory structure
-    create_view_structure(name, &viewset_name, &selected_repos)?;
+    create_view_structure(
+        name,
+        &viewset_name,
+        &selected_repos,
+        &viewset_config.repo_tags,
+    )?;
 
     ui::print_success(&format!(
         "View '{}' created successfully in viewset '{}'",
@@ -309,37 +409,195 @@ fn create_view(name: &str, viewset: Option<&str>, template: Option<&str>) -> Res
     Ok(())
 }
 
-fn delete_view(name: &str, force: bool) -> Result<()> {
-    // Try to detect current viewset or find the view in any viewset
+/// Locate a view by name: prefer the viewset the current directory is
+/// already inside, then fall back to searching every configured viewset.
+fn find_view(name: &str) -> Result<(std::path::PathBuf, String)> {
     let config = config::load_viewsets_config()?;
-    let mut view_path = None;
-    let mut found_viewset = None;
 
-    // First try current viewset
     if let Some(current_viewset) = config::detect_current_viewset() {
         let path = config::get_view_path(&current_viewset, name)?;
         if path.exists() {
-            view_path = Some(path);
-            found_viewset = Some(current_viewset);
+            return Ok((path, current_viewset));
         }
     }
 
-    // If not found, search all viewsets
-    if view_path.is_none() {
+    for viewset_name in config.viewsets.keys() {
+        let path = config::get_view_path(viewset_name, name)?;
+        if path.exists() {
+            return Ok((path, viewset_name.clone()));
+        }
+    }
+
+    anyhow::bail!("View '{}' not found in any viewset", name)
+}
+
+/// Spawn `$SHELL` with its working directory set to the view, exporting
+/// context as env vars so scripts/prompts can see which view they're in.
+/// Refuses to nest since `VIEWYARD_VIEW` would otherwise point at the wrong
+/// view once the inner shell exits back to the outer one.
+fn enter_view(name: &str) -> Result<()> {
+    if let Ok(current) = std::env::var("VIEWYARD_VIEW") {
+        anyhow::bail!(
+            "Already inside view '{}' - exit that shell before entering another view",
+            current
+        );
+    }
+
+    let (view_path, viewset_name) = find_view(name)?;
+    let shell = std::env::var("SHELL")
+        .context("$SHELL is not set - can't spawn a shell to enter the view")?;
+
+    ui::print_info(&format!(
+        "Entering view '{}' in viewset '{}' ({}) - exit the shell to return",
+        name,
+        viewset_name,
+        view_path.display()
+    ));
+
+    let active_repos = active_repo_names(&view_path).join(",");
+
+    let status = std::process::Command::new(&shell)
+        .current_dir(&view_path)
+        .env("VIEWYARD_VIEW", name)
+        .env("VIEWYARD_VIEWSET", &viewset_name)
+        .env("VIEWYARD_VIEW_ROOT", view_path.to_string_lossy().to_string())
+        .env("VIEWYARD_ACTIVE_REPOS", active_repos)
+        .status()
+        .with_context(|| format!("Failed to spawn shell '{}'", shell))?;
+
+    if !status.success() {
+        ui::print_warning("Shell exited with a non-zero status");
+    }
+    ui::print_info(&format!("Left view '{}'", name));
+
+    Ok(())
+}
+
+/// Resolve which view(s) `view update` should act on and update each one.
+fn update_view(name: Option<&str>, all: bool) -> Result<()> {
+    let config = config::load_viewsets_config()?;
+
+    let targets: Vec<(std::path::PathBuf, String)> = if all {
+        let mut all_views = Vec::new();
         for viewset_name in config.viewsets.keys() {
-            let path = config::get_view_path(viewset_name, name)?;
-            if path.exists() {
-                view_path = Some(path);
-                found_viewset = Some(viewset_name.clone());
-                break;
+            let views_dir = config::get_views_dir(viewset_name)?;
+            if !views_dir.exists() {
+                continue;
+            }
+            for entry in fs::read_dir(&views_dir)? {
+                let path = entry?.path();
+                if path.is_dir() && path.join(".git").exists() {
+                    all_views.push((path, viewset_name.clone()));
+                }
             }
         }
+        all_views
+    } else {
+        let view_name =
+            name.ok_or_else(|| anyhow::anyhow!("Specify a view name, or pass --all"))?;
+        vec![find_view(view_name)?]
+    };
+
+    if targets.is_empty() {
+        ui::print_warning("No views found to update");
+        return Ok(());
     }
 
-    let (view_path, viewset_name) = match (view_path, found_viewset) {
-        (Some(path), Some(viewset)) => (path, viewset),
-        _ => anyhow::bail!("View '{}' not found in any viewset", name),
-    };
+    for (view_path, viewset_name) in targets {
+        let view_name = view_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("?");
+        ui::print_header(&format!(
+            "Updating view '{}' in viewset '{}'",
+            view_name, viewset_name
+        ));
+
+        let viewset_config = config
+            .viewsets
+            .get(&viewset_name)
+            .ok_or_else(|| anyhow::anyhow!("Viewset '{}' not found", viewset_name))?;
+        update_view_repos(&view_path, viewset_config)?;
+    }
+
+    Ok(())
+}
+
+/// Clone each configured repo missing from `view_path` (or fetch + fast-
+/// forward it if it's already there), then warn about any on-disk submodule
+/// that isn't in the viewset config anymore.
+fn update_view_repos(view_path: &Path, viewset_config: &crate::models::Viewset) -> Result<()> {
+    let mut on_disk: std::collections::HashSet<String> = fs::read_dir(view_path)?
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.path().is_dir() && git::is_git_repo(&entry.path()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    for repo in &viewset_config.repos {
+        let repo_path = view_path.join(&repo.name);
+        on_disk.remove(&repo.name);
+
+        let is_missing = !repo_path.exists()
+            || fs::read_dir(&repo_path)
+                .map(|mut entries| entries.next().is_none())
+                .unwrap_or(true);
+
+        if is_missing {
+            ui::print_info(&format!("  {} - cloning...", repo.name));
+            match git::clone_repository(&repo.url, &repo_path) {
+                Ok(()) => ui::print_success(&format!("  {} - cloned", repo.name)),
+                Err(e) => ui::print_error(&format!("  {} - clone failed: {e}", repo.name)),
+            }
+            continue;
+        }
+
+        ui::print_info(&format!("  {} - fetching...", repo.name));
+        // Self-heal a corrupted submodule checkout (bad object DB,
+        // unresolvable HEAD after fetch) by re-cloning and retrying the
+        // fetch once; network/auth failures are surfaced as-is.
+        if let Err(e) = crate::recovery::with_recovery(&repo_path, &repo.url, git::fetch) {
+            ui::print_error(&format!("  {} - fetch failed: {e}", repo.name));
+            continue;
+        }
+
+        let default_branch = match crate::recovery::with_recovery(
+            &repo_path,
+            &repo.url,
+            git::get_default_branch,
+        ) {
+            Ok(branch) => branch,
+            Err(e) => {
+                ui::print_warning(&format!(
+                    "  {} - couldn't detect default branch: {e}",
+                    repo.name
+                ));
+                continue;
+            }
+        };
+
+        match git::merge_fast_forward(&default_branch, &repo_path) {
+            Ok(()) => ui::print_success(&format!("  {} - up to date", repo.name)),
+            Err(_) => ui::print_warning(&format!(
+                "  {} - can't fast-forward, diverged from {}: resolve manually",
+                repo.name, default_branch
+            )),
+        }
+    }
+
+    if !on_disk.is_empty() {
+        let mut orphaned: Vec<&str> = on_disk.iter().map(String::as_str).collect();
+        orphaned.sort_unstable();
+        ui::print_warning(&format!(
+            "On disk but not in viewset config: {}",
+            orphaned.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn delete_view(name: &str, force: bool) -> Result<()> {
+    let (view_path, viewset_name) = find_view(name)?;
 
     if !force {
         ui::print_warning(&format!(
@@ -364,23 +622,24 @@ fn delete_view(name: &str, force: bool) -> Result<()> {
     Ok(())
 }
 
-fn list_views(viewset: Option<&str>) -> Result<()> {
+fn list_views(viewset: Option<&str>, tag: Option<&str>) -> Result<()> {
     let config = config::load_viewsets_config()?;
 
     match viewset {
         Some(name) => {
-            if !config.viewsets.contains_key(name) {
-                anyhow::bail!("Viewset '{}' not found", name);
-            }
+            let viewset_config = config
+                .viewsets
+                .get(name)
+                .ok_or_else(|| anyhow::anyhow!("Viewset '{}' not found", name))?;
             ui::print_header(&format!("Views in viewset '{}':", name));
-            list_views_for_viewset(name)?;
+            list_views_for_viewset(name, viewset_config, tag)?;
         }
         None => {
             ui::print_header("All views:");
             let mut total_views = 0;
-            for viewset_name in config.viewsets.keys() {
+            for (viewset_name, viewset_config) in &config.viewsets {
                 ui::print_info(&format!("Viewset: {}", viewset_name));
-                let count = list_views_for_viewset(viewset_name)?;
+                let count = list_views_for_viewset(viewset_name, viewset_config, tag)?;
                 total_views += count;
             }
 
@@ -395,7 +654,11 @@ fn list_views(viewset: Option<&str>) -> Result<()> {
     Ok(())
 }
 
-fn list_views_for_viewset(viewset_name: &str) -> Result<usize> {
+fn list_views_for_viewset(
+    viewset_name: &str,
+    viewset_config: &crate::models::Viewset,
+    tag: Option<&str>,
+) -> Result<usize> {
     let views_dir = config::get_views_dir(viewset_name)?;
 
     if !views_dir.exists() {
@@ -412,7 +675,13 @@ fn list_views_for_viewset(viewset_name: &str) -> Result<usize> {
             if let Some(view_name) = path.file_name().and_then(|n| n.to_str()) {
                 // Check if it's a valid view (has .git directory)
                 if path.join(".git").exists() {
-                    ui::print_info(&format!("  {}", view_name));
+                    if let Some(tag_name) = tag {
+                        if !view_has_tag(&path, viewset_config, tag_name) {
+                            continue;
+                        }
+                    }
+                    let summary = aggregate_view_status(&path);
+                    ui::print_info(&format!("  {} {}", view_name, summary.render()));
                     view_count += 1;
                 }
             }
@@ -426,6 +695,151 @@ fn list_views_for_viewset(viewset_name: &str) -> Result<usize> {
     Ok(view_count)
 }
 
+/// Repo names active in a view, read from `.viewyard-context`; falls back to
+/// the view's submodule directories if the context file is missing or stale.
+fn active_repo_names(view_path: &Path) -> Vec<String> {
+    if let Ok(content) = fs::read_to_string(view_path.join(".viewyard-context")) {
+        if let Ok(value) = serde_yaml::from_str::<serde_yaml::Value>(&content) {
+            if let Some(names) = value.get("active_repos").and_then(|v| v.as_sequence()) {
+                return names
+                    .iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect();
+            }
+        }
+    }
+
+    git::view_status_summaries(view_path)
+        .map(|summaries| summaries.into_iter().map(|(name, _)| name).collect())
+        .unwrap_or_default()
+}
+
+/// Whether any repo active in this view carries `tag_name` per the
+/// viewset's current `repo_tags`.
+fn view_has_tag(view_path: &Path, viewset_config: &crate::models::Viewset, tag_name: &str) -> bool {
+    active_repo_names(view_path).iter().any(|name| {
+        viewset_config
+            .repo_tags
+            .get(name)
+            .map(|tags| tags.iter().any(|t| t == tag_name))
+            .unwrap_or(false)
+    })
+}
+
+/// Aggregate a view's per-submodule status into the union shown next to its
+/// name in `view list` - a failure to read status (e.g. a corrupted
+/// submodule) is reported as clean rather than aborting the whole listing.
+fn aggregate_view_status(view_path: &Path) -> git::StatusSummary {
+    let mut aggregate = git::StatusSummary::default();
+    if let Ok(submodules) = git::view_status_summaries(view_path) {
+        for (_, summary) in &submodules {
+            aggregate.merge(summary);
+        }
+    }
+    aggregate
+}
+
+fn view_status(viewset: Option<&str>) -> Result<()> {
+    let config = config::load_viewsets_config()?;
+
+    match viewset {
+        Some(name) => {
+            if !config.viewsets.contains_key(name) {
+                anyhow::bail!("Viewset '{}' not found", name);
+            }
+            ui::print_header(&format!("Status for views in viewset '{}':", name));
+            print_status_for_viewset(name)?;
+        }
+        None => {
+            ui::print_header("Status for all views:");
+            for viewset_name in config.viewsets.keys() {
+                ui::print_info(&format!("Viewset: {}", viewset_name));
+                print_status_for_viewset(viewset_name)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_status_for_viewset(viewset_name: &str) -> Result<()> {
+    let views_dir = config::get_views_dir(viewset_name)?;
+    if !views_dir.exists() {
+        ui::print_info("  No views directory found");
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(&views_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if !path.is_dir() || !path.join(".git").exists() {
+            continue;
+        }
+        let Some(view_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+
+        ui::print_info(&format!("  {view_name}:"));
+        let submodules = git::view_status_summaries(&path)?;
+        if submodules.is_empty() {
+            ui::print_info("    (no submodules)");
+            continue;
+        }
+        for (repo_name, summary) in submodules {
+            ui::print_info(&format!("    {} {}", summary.render(), repo_name));
+        }
+    }
+
+    Ok(())
+}
+
+/// Add/remove tags on a viewset, or on a single repo within one via
+/// "<viewset>/<repo>" syntax.
+fn tag_target(target: &str, add: &[String], remove: &[String]) -> Result<()> {
+    let mut config = config::load_viewsets_config()?;
+
+    let (viewset_name, repo_name) = match target.split_once('/') {
+        Some((viewset, repo)) => (viewset.to_string(), Some(repo.to_string())),
+        None => (target.to_string(), None),
+    };
+
+    let viewset_config = config
+        .viewsets
+        .get_mut(&viewset_name)
+        .ok_or_else(|| anyhow::anyhow!("Viewset '{}' not found", viewset_name))?;
+
+    let tags = match &repo_name {
+        Some(repo) => {
+            if !viewset_config.repos.iter().any(|r| r.name == *repo) {
+                anyhow::bail!("Repository '{}' not found in viewset '{}'", repo, viewset_name);
+            }
+            viewset_config.repo_tags.entry(repo.clone()).or_default()
+        }
+        None => &mut viewset_config.tags,
+    };
+
+    for new_tag in add {
+        if !tags.contains(new_tag) {
+            tags.push(new_tag.clone());
+        }
+    }
+    tags.retain(|t| !remove.contains(t));
+    tags.sort();
+
+    config::save_viewsets_config(&config)?;
+
+    match repo_name {
+        Some(repo) => ui::print_success(&format!(
+            "Updated tags for '{}' in viewset '{}'",
+            repo, viewset_name
+        )),
+        None => ui::print_success(&format!("Updated tags for viewset '{}'", viewset_name)),
+    }
+
+    Ok(())
+}
+
 fn validate_config() -> Result<()> {
     ui::print_info("Validating viewsets configuration...");
 
@@ -439,6 +853,8 @@ fn validate_config() -> Result<()> {
         anyhow::bail!("No viewsets configured");
     }
 
+    let mut invalid_count = 0;
+
     for (name, viewset) in &config.viewsets {
         ui::print_info(&format!("Validating viewset '{}':", name));
 
@@ -448,20 +864,145 @@ fn validate_config() -> Result<()> {
         }
 
         for repo in &viewset.repos {
-            ui::print_info(&format!("  ‚úì {}: {}", repo.name, repo.url));
+            match git::parse_remote(&repo.url) {
+                Ok(remote) => {
+                    ui::print_info(&format!(
+                        "  {}: {} ({}/{} on {})",
+                        repo.name, repo.url, remote.owner, remote.repo, remote.host
+                    ));
+                }
+                Err(e) => {
+                    invalid_count += 1;
+                    ui::print_error(&format!(
+                        "  [{}] {}: invalid repository URL '{}' - {e}",
+                        name, repo.name, repo.url
+                    ));
+                }
+            }
         }
     }
 
+    if invalid_count > 0 {
+        anyhow::bail!(
+            "Configuration has {invalid_count} invalid repository URL(s) - see diagnostics above"
+        );
+    }
+
     ui::print_success("Configuration is valid");
     Ok(())
 }
 
+/// Check, for every repo active in view `name`, that `main` is an ancestor of
+/// (or equal to) `next`, and `next` is an ancestor of (or equal to) `dev` -
+/// viewyard's trunk-based ancestry invariant - printing a per-repo table of
+/// ahead/behind/diverged positions so drift is visible before
+/// `rebase`/`push-all`. Branch names default to `main`/`next`/`dev` and are
+/// overridable per repo via `Viewset::trunk_branches`.
+fn validate_view_branches(name: &str) -> Result<()> {
+    let (view_path, viewset_name) = find_view(name)?;
+    let config = config::load_viewsets_config()?;
+    let viewset_config = config
+        .viewsets
+        .get(&viewset_name)
+        .ok_or_else(|| anyhow::anyhow!("Viewset '{}' not found", viewset_name))?;
+
+    ui::print_header(&format!("Trunk branch positions for view '{}':", name));
+
+    let mut out_of_sync = 0;
+
+    for (repo_name, _) in git::view_status_summaries(&view_path)? {
+        let repo_path = view_path.join(&repo_name);
+        let trunk = viewset_config
+            .trunk_branches
+            .get(&repo_name)
+            .cloned()
+            .unwrap_or_default();
+
+        let main_to_next = git::branch_position(&trunk.main, &trunk.next, &repo_path);
+        let next_to_dev = git::branch_position(&trunk.next, &trunk.dev, &repo_path);
+
+        match (main_to_next, next_to_dev) {
+            (Ok(main_to_next), Ok(next_to_dev)) => {
+                if is_in_trunk_order(&main_to_next) && is_in_trunk_order(&next_to_dev) {
+                    ui::print_success(&format!(
+                        "  {repo_name}: {} -> {} -> {} in sync",
+                        trunk.main, trunk.next, trunk.dev
+                    ));
+                } else {
+                    out_of_sync += 1;
+                    ui::print_warning(&format!("  {repo_name}: out of sync"));
+                    report_branch_position(&trunk.main, &trunk.next, &main_to_next);
+                    report_branch_position(&trunk.next, &trunk.dev, &next_to_dev);
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                out_of_sync += 1;
+                ui::print_error(&format!("  {repo_name}: {e}"));
+            }
+        }
+    }
+
+    if out_of_sync > 0 {
+        anyhow::bail!("{out_of_sync} repo(s) have an out-of-sync main/next/dev trunk");
+    }
+
+    ui::print_success("All repos have a synchronized main/next/dev trunk");
+    Ok(())
+}
+
+/// Whether `position` satisfies "base is an ancestor of (or equal to) head".
+fn is_in_trunk_order(position: &git::BranchPosition) -> bool {
+    matches!(
+        position,
+        git::BranchPosition::Equal | git::BranchPosition::Ahead(_)
+    )
+}
 
+fn report_branch_position(base: &str, head: &str, position: &git::BranchPosition) {
+    match position {
+        git::BranchPosition::Equal => ui::print_info(&format!("    {head} == {base}")),
+        git::BranchPosition::Ahead(commits) => {
+            ui::print_info(&format!(
+                "    {head} is ahead of {base} by {} commit(s)",
+                commits.len()
+            ));
+            print_commit_sample(commits);
+        }
+        git::BranchPosition::Behind(commits) => {
+            ui::print_warning(&format!(
+                "    {head} is behind {base} by {} commit(s) - expected {head} to be at or ahead of {base}",
+                commits.len()
+            ));
+            print_commit_sample(commits);
+        }
+        git::BranchPosition::Diverged { ahead, behind } => {
+            ui::print_warning(&format!(
+                "    {head} has diverged from {base}: {} commit(s) ahead, {} commit(s) behind",
+                ahead.len(),
+                behind.len()
+            ));
+            print_commit_sample(ahead);
+            print_commit_sample(behind);
+        }
+    }
+}
+
+/// Print up to the first 5 commits of `commits`, with a "... and N more" tail.
+fn print_commit_sample(commits: &[String]) {
+    const SAMPLE_SIZE: usize = 5;
+    for commit in commits.iter().take(SAMPLE_SIZE) {
+        println!("      {commit}");
+    }
+    if commits.len() > SAMPLE_SIZE {
+        println!("      ... and {} more", commits.len() - SAMPLE_SIZE);
+    }
+}
 
 fn create_view_structure(
     view_name: &str,
     viewset_name: &str,
     selected_repos: &[&Repository],
+    repo_tags: &std::collections::HashMap<String, Vec<String>>,
 ) -> Result<()> {
     let view_path = config::get_view_path(viewset_name, view_name)?;
 
@@ -497,7 +1038,7 @@ fn create_view_structure(
     git::update_submodules(&view_path)?;
 
     // Create view context file
-    create_view_context(&view_path, view_name, selected_repos)?;
+    create_view_context(&view_path, view_name, selected_repos, repo_tags)?;
 
     ui::print_success("View structure created successfully");
     Ok(())
@@ -509,6 +1050,7 @@ fn create_view_context(
     view_path: &Path,
     view_name: &str,
     selected_repos: &[&Repository],
+    repo_tags: &std::collections::HashMap<String, Vec<String>>,
 ) -> Result<()> {
     use std::time::SystemTime;
 
@@ -536,6 +1078,23 @@ fn create_view_context(
                     .collect(),
             ),
         );
+        map.insert(
+            serde_yaml::Value::String("repo_tags".to_string()),
+            serde_yaml::Value::Mapping(
+                selected_repos
+                    .iter()
+                    .filter_map(|r| repo_tags.get(&r.name).map(|tags| (r, tags)))
+                    .map(|(r, tags)| {
+                        (
+                            serde_yaml::Value::String(r.name.clone()),
+                            serde_yaml::Value::Sequence(
+                                tags.iter().cloned().map(serde_yaml::Value::String).collect(),
+                            ),
+                        )
+                    })
+                    .collect(),
+            ),
+        );
         map.insert(
             serde_yaml::Value::String("created".to_string()),
             serde_yaml::Value::String(timestamp.to_string()),
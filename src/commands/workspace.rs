@@ -2,62 +2,20 @@ use anyhow::{Context, Result};
 use clap::Subcommand;
 use std::path::Path;
 
+use crate::backend::BackendKind;
 use crate::git;
 use crate::models;
+use crate::oplog;
+use crate::rebase_state::{self, RebaseState};
+use crate::repos_file;
+use crate::selector;
 use crate::ui;
 
-/// Validate and load repository configuration from JSON file
+/// Validate and load repository configuration, accepting either
+/// `.viewyard-repos.json` or `.viewyard-repos.toml` (format inferred from
+/// `repos_file`'s extension).
 fn load_and_validate_repos(repos_file: &Path) -> Result<Vec<models::Repository>> {
-    let repos_json = std::fs::read_to_string(repos_file).with_context(|| {
-        format!(
-            "Failed to read configuration file: {}",
-            repos_file.display()
-        )
-    })?;
-
-    let repositories: Vec<models::Repository> = serde_json::from_str(&repos_json)
-        .with_context(|| {
-            format!(
-                "Invalid JSON in configuration file: {}\n\
-                Expected format: array of repository objects with 'name', 'url', 'is_private', and 'source' fields",
-                repos_file.display()
-            )
-        })?;
-
-    // Validate each repository entry
-    for (index, repo) in repositories.iter().enumerate() {
-        if repo.name.trim().is_empty() {
-            anyhow::bail!(
-                "Invalid repository at index {}: 'name' field cannot be empty\n\
-                File: {}",
-                index,
-                repos_file.display()
-            );
-        }
-
-        if repo.url.trim().is_empty() {
-            anyhow::bail!(
-                "Invalid repository at index {}: 'url' field cannot be empty\n\
-                Repository: {}\n\
-                File: {}",
-                index,
-                repo.name,
-                repos_file.display()
-            );
-        }
-
-        // Basic URL validation - should contain git-like patterns
-        if !repo.url.contains("git") && !repo.url.contains("github") && !repo.url.contains("gitlab")
-        {
-            ui::print_warning(&format!(
-                "Repository '{}' has unusual URL format: {}\n\
-                This might not be a valid Git repository URL",
-                repo.name, repo.url
-            ));
-        }
-    }
-
-    Ok(repositories)
+    crate::repos_file::load_and_validate(repos_file)
 }
 
 fn repo_directory_name(repo: &models::Repository) -> &str {
@@ -71,122 +29,371 @@ fn resolve_repo_path(view_root: &Path, repo: &models::Repository) -> std::path::
 #[derive(Subcommand)]
 pub enum WorkspaceCommand {
     /// Show status of all repos in current view
-    Status,
+    Status {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
+        /// Automatically check out the view's branch in repos found on the
+        /// wrong branch, creating it from the default branch if needed.
+        /// Repos with uncommitted changes are skipped rather than touched.
+        #[arg(long)]
+        fix: bool,
+        /// Number of repos to check concurrently (default: available parallelism)
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
     /// Rebase repos against their default branch
-    Rebase,
+    Rebase {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view. Ignored with `--continue`/`--abort`.
+        #[arg(long)]
+        select: Option<String>,
+        /// Resume a rebase batch that previously stopped on a conflict
+        #[arg(long = "continue")]
+        continue_: bool,
+        /// Abort the repo the batch stopped on and discard rebase progress
+        #[arg(long)]
+        abort: bool,
+    },
     /// Commit to all dirty repos (only repos with changes)
     #[command(name = "commit-all")]
     CommitAll {
         /// Commit message
         message: String,
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
     },
     /// Push repos with commits ahead (only repos with unpushed commits)
     #[command(name = "push-all")]
-    PushAll,
+    PushAll {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
+    },
+    /// Continuously re-poll every repo in the current view, reporting what changed
+    Watch {
+        /// Seconds to sleep between polls
+        #[arg(long, default_value_t = DEFAULT_WATCH_INTERVAL_SECS)]
+        interval: u64,
+    },
+    /// Undo the most recent rebase, commit-all, or push-all
+    Undo {
+        /// Restore repos even if they have uncommitted changes
+        #[arg(long)]
+        force: bool,
+    },
 }
 
+/// Default `watch` poll interval, overridable via `--interval`.
+const DEFAULT_WATCH_INTERVAL_SECS: u64 = 30;
+
 /// Handle workspace commands that operate on all repositories in the current view
 ///
 /// These commands must be run from within a view directory and will validate
 /// that all repositories are synchronized on the same branch before proceeding.
 pub fn handle_command(command: WorkspaceCommand) -> Result<()> {
+    handle_command_with_backend(command, BackendKind::default())
+}
+
+/// Handle workspace commands using an explicitly selected git backend
+/// (shell, gitoxide, or auto-detected) for the status/commit/push work.
+pub fn handle_command_with_backend(command: WorkspaceCommand, backend_kind: BackendKind) -> Result<()> {
     match command {
-        WorkspaceCommand::Status => workspace_status(),
-        WorkspaceCommand::Rebase => workspace_rebase(),
-        WorkspaceCommand::CommitAll { message } => workspace_commit_all(&message),
-        WorkspaceCommand::PushAll => workspace_push_all(),
+        WorkspaceCommand::Status { select, fix, jobs } => {
+            workspace_status(backend_kind, select.as_deref(), fix, jobs)
+        }
+        WorkspaceCommand::Rebase {
+            select,
+            continue_,
+            abort,
+        } => workspace_rebase(backend_kind, select.as_deref(), continue_, abort),
+        WorkspaceCommand::CommitAll { message, select } => {
+            workspace_commit_all(&message, backend_kind, select.as_deref())
+        }
+        WorkspaceCommand::PushAll { select } => workspace_push_all(backend_kind, select.as_deref()),
+        WorkspaceCommand::Watch { interval } => watch(backend_kind, interval),
+        WorkspaceCommand::Undo { force } => workspace_undo(force),
     }
 }
 
-fn workspace_status() -> Result<()> {
-    ui::print_header("Repository Status");
+/// Narrow `view_context.active_repos` down to those matching `select`
+/// (a [`selector`] expression), querying `backend` lazily and caching each
+/// repo's git facts so a compound expression like `dirty & ahead` only
+/// checks each underlying condition once per repo. A `None`/empty `select`
+/// leaves `active_repos` untouched.
+fn apply_selector(
+    view_context: &mut ViewContext,
+    backend: &dyn crate::backend::GitBackend,
+    select: Option<&str>,
+) -> Result<()> {
+    let expr = selector::parse_selector(select)?;
+    if expr == selector::SelectorExpr::All {
+        return Ok(());
+    }
 
-    // Detect current view
+    let view_root = view_context.view_root.clone();
+    view_context.active_repos.retain(|repo| {
+        let repo_path = resolve_repo_path(&view_root, repo);
+        let facts = selector::RepoFacts::new(backend, repo, &repo_path);
+        expr.matches(&facts)
+    });
+
+    Ok(())
+}
+
+fn workspace_undo(force: bool) -> Result<()> {
     let current_dir = std::env::current_dir()?;
     let view_context =
-        load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard status'")?;
+        load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard undo'")?;
+
+    let repo_paths: std::collections::HashMap<String, std::path::PathBuf> = view_context
+        .active_repos
+        .iter()
+        .map(|repo| (repo.name.clone(), resolve_repo_path(&view_context.view_root, repo)))
+        .collect();
 
-    ui::print_info(&format!("Viewset: {}", view_context.viewset_name));
-    ui::print_info(&format!("View: {}", view_context.view_name));
-    ui::print_info(&format!("Root: {}", view_context.view_root.display()));
+    oplog::undo_last_operation(&view_context.view_root, &repo_paths, force)
+}
+
+fn workspace_status(
+    backend_kind: BackendKind,
+    select: Option<&str>,
+    fix: bool,
+    jobs: Option<usize>,
+) -> Result<()> {
+    // `--format json` suppresses every prose print in this function - the
+    // result is meant to be piped straight into a JSON parser, so nothing
+    // but the final `ui::print_json` call may touch stdout.
+    let json_format = ui::output_format() == ui::OutputFormat::Json;
+
+    if !json_format {
+        ui::print_header("Repository Status");
+    }
+    let backend = backend_kind.resolve();
+
+    // Detect current view
+    let current_dir = std::env::current_dir()?;
+    let mut view_context =
+        load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard status'")?;
+    // Captured before the selector narrows `active_repos`, since the
+    // unmanaged-repo check needs the full manifest to know what's expected.
+    let all_managed_repos = view_context.active_repos.clone();
+    apply_selector(&mut view_context, backend.as_ref(), select)?;
+
+    if !json_format {
+        ui::print_info(&format!("Viewset: {}", view_context.viewset_name));
+        ui::print_info(&format!("View: {}", view_context.view_name));
+        ui::print_info(&format!("Root: {}", view_context.view_root.display()));
+    }
 
     if view_context.active_repos.is_empty() {
+        if json_format {
+            return ui::print_json(&models::ViewStatus {
+                viewset: view_context.viewset_name,
+                view: view_context.view_name,
+                repos: Vec::new(),
+            });
+        }
         ui::print_warning("No repositories in this view");
         return Ok(());
     }
 
-    // Validate branch synchronization
-    if let Err(e) = validate_branch_synchronization(&view_context) {
-        ui::print_warning(&format!("Branch synchronization check failed: {e}"));
-        ui::print_info("Continuing with status check...");
+    // Validate branch synchronization. In JSON mode the same check runs
+    // without `validate_branch_synchronization`'s prose output.
+    let sync_check = if json_format {
+        let (branches, errors) = collect_branches(&view_context);
+        if errors.is_empty() && branches.values().all(|b| *b == view_context.view_name) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Branch synchronization failed"))
+        }
+    } else {
+        validate_branch_synchronization(&view_context)
+    };
+    match sync_check {
+        Ok(()) => {}
+        Err(e) if fix => {
+            if !json_format {
+                ui::print_warning(&format!("Branch synchronization check failed: {e}"));
+            }
+            fix_branch_synchronization(&view_context, backend.as_ref(), json_format);
+        }
+        Err(e) => {
+            if !json_format {
+                ui::print_warning(&format!("Branch synchronization check failed: {e}"));
+                ui::print_info("Continuing with status check...");
+            }
+        }
     }
 
-    ui::print_info("");
+    if !json_format {
+        report_unmanaged_repos(&view_context.view_root, &all_managed_repos);
+        ui::print_info("");
+    }
 
-    // Collect branch information for consistency check
-    let mut repo_branches = Vec::new();
-    let mut clean_count = 0;
-    let mut dirty_count = 0;
-    let mut ahead_count = 0;
+    // Fetch each repo's status concurrently (status is read-only, so repos
+    // don't contend with each other), then print in view order so the
+    // report looks identical to the sequential version regardless of which
+    // repo's git invocation happened to finish first.
+    let worker_count = crate::parallel::resolve_worker_count(view_context.active_repos.len(), jobs);
+    let view_root = view_context.view_root.clone();
+    let results = crate::parallel::parallel_map(
+        view_context.active_repos.clone(),
+        worker_count,
+        |repo| {
+            let repo_path = resolve_repo_path(&view_root, &repo);
+            let repo_dir_name = repo_directory_name(&repo);
+
+            if let Err(e) = git::validate_repository_directory(&repo_path, repo_dir_name) {
+                return (repo.name.clone(), String::new(), None, Err(e));
+            }
 
-    for repo in &view_context.active_repos {
-        let repo_path = resolve_repo_path(&view_context.view_root, repo);
-        let repo_dir_name = repo_directory_name(repo);
+            if let Err(e) = git::validate_repository_for_operations(&repo_path, &repo) {
+                if json_format {
+                    ui::print_error(&format!("{}: Git configuration issue - {}", repo.name, e));
+                } else {
+                    ui::print_warning(&format!("{}: Git configuration issue - {}", repo.name, e));
+                }
+            }
 
-        // Validate directory exists
-        if let Err(e) = git::validate_repository_directory(&repo_path, repo_dir_name) {
-            ui::print_warning(&format!("{}: {}", repo.name, e));
-            continue;
-        }
+            let branch = backend
+                .get_current_branch(&repo_path)
+                .unwrap_or_else(|_| "unknown".to_string());
+            let branch_timestamp = git::get_branch_head_timestamp(&repo_path);
 
-        // Validate git repository and user configuration (but don't fail on config issues for status)
-        if let Err(e) = git::validate_repository_for_operations(&repo_path, repo) {
-            ui::print_warning(&format!("{}: Git configuration issue - {}", repo.name, e));
-            // Continue with status check even if git config has issues
-        }
+            let status_result = crate::recovery::with_recovery(&repo_path, &repo.url, |path| {
+                get_repo_status(backend.as_ref(), path, &repo.name)
+            });
 
-        // Get branch for consistency check
-        let branch = git::get_current_branch(&repo_path).unwrap_or_else(|_| "unknown".to_string());
-        repo_branches.push((repo.name.clone(), branch.clone()));
+            (repo.name.clone(), branch, branch_timestamp, status_result)
+        },
+    );
 
-        // Get repository status
-        match get_repo_status(&repo_path, &repo.name) {
-            Ok(Some(status)) => {
-                println!("{status}");
-                if status.contains("changes") {
-                    dirty_count += 1;
-                }
-                if status.contains("ahead") {
-                    ahead_count += 1;
+    let mut repo_branches = Vec::new();
+    let mut repo_statuses = Vec::new();
+    let mut counts = StatusCounts::default();
+
+    for (repo_name, branch, branch_timestamp, status_result) in results {
+        match status_result {
+            Ok(Some((status, summary))) => {
+                repo_branches.push((repo_name.clone(), branch.clone(), branch_timestamp));
+                if json_format {
+                    repo_statuses.push(repo_status_from_summary(repo_name, branch, &summary));
+                } else {
+                    println!("{status}");
                 }
+                counts.tally(&summary);
             }
             Ok(None) => {
-                // Show clean repos too
-                println!("✓ {} ({}) - clean", repo.name, branch);
-                clean_count += 1;
+                repo_branches.push((repo_name.clone(), branch.clone(), branch_timestamp));
+                if json_format {
+                    repo_statuses.push(repo_status_from_summary(repo_name, branch, &git::StatusSummary::default()));
+                } else {
+                    println!("✓ {repo_name} ({branch}) - clean");
+                }
+                counts.clean += 1;
             }
             Err(e) => {
-                ui::print_warning(&format!("{}: Error getting status - {}", repo.name, e));
+                if json_format {
+                    ui::print_error(&format!("{repo_name}: Error getting status - {e}"));
+                } else {
+                    ui::print_warning(&format!("{repo_name}: Error getting status - {e}"));
+                }
             }
         }
     }
 
+    if json_format {
+        return ui::print_json(&models::ViewStatus {
+            viewset: view_context.viewset_name,
+            view: view_context.view_name,
+            repos: repo_statuses,
+        });
+    }
+
     // Check branch consistency and show summary
     check_branch_consistency(&repo_branches);
-    show_status_summary(clean_count, dirty_count, ahead_count, &repo_branches);
+    show_status_summary(&counts, &repo_branches);
 
     Ok(())
 }
 
-fn workspace_rebase() -> Result<()> {
-    ui::print_header("Rebasing repositories");
+/// Per-view tally of [`git::StatusSummary`] flags across repos, derived from
+/// `git status --porcelain=v2 --branch` plus `git stash list`. Diverged and
+/// conflicted are tracked separately from plain ahead/behind and dirty since
+/// they're the states that actually block the synchronized-branch workflow.
+#[derive(Debug, Default)]
+struct StatusCounts {
+    clean: usize,
+    dirty: usize,
+    ahead: usize,
+    behind: usize,
+    diverged: usize,
+    conflicted: usize,
+    untracked: usize,
+    stashed: usize,
+}
+
+impl StatusCounts {
+    fn tally(&mut self, summary: &git::StatusSummary) {
+        if summary.unstaged_modified || summary.staged_added || summary.staged_renamed || summary.staged_deleted {
+            self.dirty += 1;
+        }
+        match (summary.ahead > 0, summary.behind > 0) {
+            (true, true) => self.diverged += 1,
+            (true, false) => self.ahead += 1,
+            (false, true) => self.behind += 1,
+            (false, false) => {}
+        }
+        if summary.unmerged {
+            self.conflicted += 1;
+        }
+        if summary.untracked {
+            self.untracked += 1;
+        }
+        if summary.stashed {
+            self.stashed += 1;
+        }
+    }
+}
 
+fn workspace_rebase(backend_kind: BackendKind, select: Option<&str>, continue_: bool, abort: bool) -> Result<()> {
+    if continue_ && abort {
+        anyhow::bail!("--continue and --abort cannot be used together");
+    }
+
+    let backend = backend_kind.resolve();
     let current_dir = std::env::current_dir()?;
     let view_context =
         load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard rebase'")?;
 
-    let mut rebased_repos = Vec::new();
-    let mut error_repos = Vec::new();
+    if abort {
+        return rebase_abort(&view_context);
+    }
+    if continue_ {
+        ui::print_header("Resuming rebase");
+        return rebase_continue(&view_context, backend.as_ref());
+    }
+
+    ui::print_header("Rebasing repositories");
+
+    if rebase_state::load(&view_context.view_root)?.is_some() {
+        anyhow::bail!(
+            "A previous rebase batch is still in progress. Run 'viewyard rebase --continue' or 'viewyard rebase --abort' first."
+        );
+    }
+
+    let mut view_context = view_context;
+    apply_selector(&mut view_context, backend.as_ref(), select)?;
+
     let mut repos_to_rebase: Vec<models::Repository> = Vec::new();
 
     // First pass: validate repositories and git configuration
@@ -219,26 +426,162 @@ fn workspace_rebase() -> Result<()> {
         repos_to_rebase.len()
     ));
 
-    // Second pass: perform rebase operations
-    for repo in repos_to_rebase {
+    let snapshot_repos: Vec<(String, std::path::PathBuf)> = repos_to_rebase
+        .iter()
+        .map(|repo| (repo.name.clone(), resolve_repo_path(&view_context.view_root, repo)))
+        .collect();
+    if let Err(e) = oplog::record_snapshot(&view_context.view_root, oplog::OperationKind::Rebase, &snapshot_repos) {
+        ui::print_warning(&format!("Failed to record undo snapshot: {e}"));
+    }
+
+    run_rebase_batch(&view_context, backend.as_ref(), Vec::new(), repos_to_rebase)
+}
+
+/// Resume a rebase batch that previously stopped, picking up at the repo
+/// recorded in [`rebase_state::RebaseState::stopped_at`]: if it's still mid
+/// rebase (`.git/rebase-merge`/`.git/rebase-apply` present), run `git rebase
+/// --continue`; otherwise the stop wasn't a conflict (e.g. a fetch failure),
+/// so just retry the rebase from scratch. Either way, the repos already
+/// rebased before the stop are never re-rebased.
+fn rebase_continue(view_context: &ViewContext, backend: &dyn crate::backend::GitBackend) -> Result<()> {
+    let Some(state) = rebase_state::load(&view_context.view_root)? else {
+        anyhow::bail!("No in-progress rebase to continue");
+    };
+
+    let repo_by_name: std::collections::HashMap<&str, &models::Repository> = view_context
+        .active_repos
+        .iter()
+        .map(|r| (r.name.as_str(), r))
+        .collect();
+
+    let stopped_repo = *repo_by_name.get(state.stopped_at.as_str()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Repository '{}' from the rebase state is no longer in this view",
+            state.stopped_at
+        )
+    })?;
+    let stopped_path = resolve_repo_path(&view_context.view_root, stopped_repo);
+
+    ui::print_info(&format!("Continuing {}", state.stopped_at));
+
+    if git::is_rebase_in_progress(&stopped_path) {
+        git::rebase_continue(&stopped_path)?;
+    } else {
+        rebase_repo(backend, &stopped_path)?;
+    }
+
+    ui::print_success(&format!("{}: Rebased successfully", state.stopped_at));
+
+    let mut rebased_repos = state.rebased;
+    rebased_repos.push(state.stopped_at);
+
+    let mut pending_repos = Vec::new();
+    for name in &state.pending {
+        let repo = repo_by_name.get(name.as_str()).ok_or_else(|| {
+            anyhow::anyhow!("Repository '{name}' from the rebase state is no longer in this view")
+        })?;
+        pending_repos.push((*repo).clone());
+    }
+
+    run_rebase_batch(view_context, backend, rebased_repos, pending_repos)
+}
+
+/// Abort the repo a rebase batch stopped on (running `git rebase --abort`
+/// when it's actually mid-rebase) and discard the rest of the batch.
+fn rebase_abort(view_context: &ViewContext) -> Result<()> {
+    let Some(state) = rebase_state::load(&view_context.view_root)? else {
+        anyhow::bail!("No in-progress rebase to abort");
+    };
+
+    let stopped_repo = view_context
+        .active_repos
+        .iter()
+        .find(|r| r.name == state.stopped_at)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Repository '{}' from the rebase state is no longer in this view",
+                state.stopped_at
+            )
+        })?;
+    let stopped_path = resolve_repo_path(&view_context.view_root, stopped_repo);
+
+    if git::is_rebase_in_progress(&stopped_path) {
+        git::rebase_abort(&stopped_path)?;
+        ui::print_success(&format!("{}: Rebase aborted", state.stopped_at));
+    } else {
+        ui::print_info(&format!(
+            "{}: Not mid-rebase; nothing to abort in the repository itself",
+            state.stopped_at
+        ));
+    }
+
+    rebase_state::clear(&view_context.view_root)?;
+    ui::print_info(&format!(
+        "Discarded rebase batch ({} repos were never attempted)",
+        state.pending.len()
+    ));
+
+    Ok(())
+}
+
+/// Rebase `pending` repos in order, stopping at the first failure and
+/// persisting progress to [`rebase_state`] so `viewyard rebase
+/// --continue`/`--abort` can finish the batch later. `already_rebased`
+/// seeds the cumulative summary when resuming a previous invocation.
+fn run_rebase_batch(
+    view_context: &ViewContext,
+    backend: &dyn crate::backend::GitBackend,
+    already_rebased: Vec<String>,
+    pending: Vec<models::Repository>,
+) -> Result<()> {
+    let mut rebased_repos = already_rebased;
+
+    for (i, repo) in pending.iter().enumerate() {
         let repo_name = repo.name.clone();
-        let repo_path = resolve_repo_path(&view_context.view_root, &repo);
+        let repo_path = resolve_repo_path(&view_context.view_root, repo);
 
         ui::print_info(&format!("Rebasing {repo_name}"));
 
-        match rebase_repo(&repo_path) {
+        // Self-heal a corrupted working copy (bad object DB, unresolvable
+        // HEAD after fetch) by re-cloning from `repo.url` and retrying the
+        // rebase once; network/auth failures are never treated this way.
+        match crate::recovery::with_recovery(&repo_path, &repo.url, |p| rebase_repo(backend, p)) {
             Ok(()) => {
                 ui::print_success(&format!("{repo_name}: Rebased successfully"));
                 rebased_repos.push(repo_name);
             }
             Err(e) => {
                 ui::print_error(&format!("{repo_name}: Failed to rebase - {e}"));
-                error_repos.push((repo_name, e.to_string()));
+
+                let remaining_pending: Vec<String> =
+                    pending[i + 1..].iter().map(|r| r.name.clone()).collect();
+                let state = RebaseState {
+                    rebased: rebased_repos.clone(),
+                    stopped_at: repo_name.clone(),
+                    pending: remaining_pending,
+                };
+                if let Err(save_err) = rebase_state::save(&view_context.view_root, &state) {
+                    ui::print_warning(&format!("Failed to save rebase state: {save_err}"));
+                }
+
+                if !rebased_repos.is_empty() {
+                    ui::print_success(&format!(
+                        "Successfully rebased {} repositories so far: {}",
+                        rebased_repos.len(),
+                        rebased_repos.join(", ")
+                    ));
+                }
+                ui::print_info("Resolve the issue, then run:");
+                ui::print_info("   viewyard rebase --continue   to resume the batch");
+                ui::print_info("   viewyard rebase --abort      to discard the rest of the batch");
+
+                anyhow::bail!("Rebase stopped on '{repo_name}': {e}");
             }
         }
     }
 
-    // Summary
+    rebase_state::clear(&view_context.view_root)?;
+
     if !rebased_repos.is_empty() {
         ui::print_success(&format!(
             "Successfully rebased {} repositories: {}",
@@ -247,26 +590,17 @@ fn workspace_rebase() -> Result<()> {
         ));
     }
 
-    if !error_repos.is_empty() {
-        ui::print_error(&format!(
-            "Failed to rebase {} repositories",
-            error_repos.len()
-        ));
-        for (repo, error) in &error_repos {
-            ui::print_error(&format!("   {repo}: {error}"));
-        }
-        anyhow::bail!("Some repositories failed to rebase");
-    }
-
     Ok(())
 }
 
-fn workspace_commit_all(message: &str) -> Result<()> {
+fn workspace_commit_all(message: &str, backend_kind: BackendKind, select: Option<&str>) -> Result<()> {
     ui::print_header(&format!("Committing repositories with changes: {message}"));
+    let backend = backend_kind.resolve();
 
     let current_dir = std::env::current_dir()?;
-    let view_context =
+    let mut view_context =
         load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard commit-all'")?;
+    apply_selector(&mut view_context, backend.as_ref(), select)?;
 
     let mut committed_repos = Vec::new();
     let mut repos_to_commit: Vec<models::Repository> = Vec::new();
@@ -288,7 +622,7 @@ fn workspace_commit_all(message: &str) -> Result<()> {
             continue;
         }
 
-        match git::has_uncommitted_changes(&repo_path) {
+        match backend.has_uncommitted_changes(&repo_path) {
             Ok(true) => {
                 repos_to_commit.push(repo.clone());
             }
@@ -311,13 +645,21 @@ fn workspace_commit_all(message: &str) -> Result<()> {
         repos_to_commit.len()
     ));
 
+    let snapshot_repos: Vec<(String, std::path::PathBuf)> = repos_to_commit
+        .iter()
+        .map(|repo| (repo.name.clone(), resolve_repo_path(&view_context.view_root, repo)))
+        .collect();
+    if let Err(e) = oplog::record_snapshot(&view_context.view_root, oplog::OperationKind::CommitAll, &snapshot_repos) {
+        ui::print_warning(&format!("Failed to record undo snapshot: {e}"));
+    }
+
     // Second pass: commit changes with rollback on failure
     for repo in &repos_to_commit {
         let repo_name = &repo.name;
         let repo_path = resolve_repo_path(&view_context.view_root, repo);
 
         ui::print_info(&format!("Committing changes in {repo_name}"));
-        match commit_repo_changes(&repo_path, message) {
+        match backend.commit_all(&repo_path, message) {
             Ok(()) => {
                 committed_repos.push(repo_name.clone());
             }
@@ -369,72 +711,21 @@ fn commit_repo_changes(repo_path: &Path, message: &str) -> Result<()> {
     Ok(())
 }
 
-fn rebase_repo(repo_path: &Path) -> Result<()> {
-    // Check for clean working directory first
-    if git::has_uncommitted_changes(repo_path)? {
-        anyhow::bail!(
-            "Cannot rebase with uncommitted changes. Please commit or stash your changes first."
-        );
-    }
-
-    // First, fetch the latest changes
-    git::fetch(repo_path)?;
-
-    // Get the current branch name
-    let current_branch = git::get_current_branch(repo_path)?;
-
-    // Dynamically detect the default branch for this repository
-    let rebase_target = git::get_default_branch(repo_path)
-        .with_context(|| "Failed to detect default branch for repository")?;
-
-    // Extract the branch name from the rebase target (e.g., "origin/main" -> "main")
-    let target_branch_name = rebase_target
-        .strip_prefix("origin/")
-        .unwrap_or(&rebase_target);
-
-    // Check if we're already on the target branch
-    if current_branch == target_branch_name {
-        // If we're on the default branch, just fast-forward merge
-        git::merge_fast_forward(&rebase_target, repo_path)?;
-        Ok(())
-    } else {
-        // Attempt rebase with conflict detection
-        match git::rebase(&rebase_target, repo_path) {
-            Ok(()) => Ok(()),
-            Err(e) => {
-                // Check if we're in a rebase state (conflict occurred)
-                if repo_path.join(".git/rebase-merge").exists()
-                    || repo_path.join(".git/rebase-apply").exists()
-                {
-                    ui::print_error("Rebase conflict detected!");
-                    ui::print_info("Manual resolution required:");
-                    ui::print_info("   1. Navigate to the repository:");
-                    ui::print_info(&format!("      cd {}", repo_path.display()));
-                    ui::print_info("   2. Resolve conflicts in the affected files");
-                    ui::print_info("   3. Stage resolved files: git add <file>");
-                    ui::print_info("   4. Continue rebase: git rebase --continue");
-                    ui::print_info("   5. Or abort rebase: git rebase --abort");
-                    ui::print_info("");
-                    ui::print_info("Common conflict resolution:");
-                    ui::print_info("   • Edit files to resolve <<<< ==== >>>> markers");
-                    ui::print_info("   • Use 'git status' to see conflicted files");
-                    ui::print_info("   • Use 'git diff' to see conflict details");
-
-                    anyhow::bail!("Rebase conflict requires manual resolution")
-                }
-                // Some other rebase error
-                Err(e).context("Rebase failed")
-            }
-        }
-    }
+/// Fetch, then fast-forward or rebase onto the detected default branch,
+/// through `backend` so unit tests can exercise this against
+/// [`crate::backend::mock::MockBackend`] instead of a real repository.
+fn rebase_repo(backend: &dyn crate::backend::GitBackend, repo_path: &Path) -> Result<()> {
+    backend.rebase(repo_path)
 }
 
-fn workspace_push_all() -> Result<()> {
+fn workspace_push_all(backend_kind: BackendKind, select: Option<&str>) -> Result<()> {
     ui::print_header("Pushing repositories with unpushed commits");
+    let backend = backend_kind.resolve();
 
     let current_dir = std::env::current_dir()?;
-    let view_context =
+    let mut view_context =
         load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard push-all'")?;
+    apply_selector(&mut view_context, backend.as_ref(), select)?;
 
     let mut pushed_repos = Vec::new();
     let mut repos_to_push: Vec<models::Repository> = Vec::new();
@@ -456,7 +747,7 @@ fn workspace_push_all() -> Result<()> {
             continue;
         }
 
-        match git::has_unpushed_commits(&repo_path) {
+        match backend.has_unpushed_commits(&repo_path) {
             Ok(true) => {
                 repos_to_push.push(repo.clone());
             }
@@ -482,13 +773,21 @@ fn workspace_push_all() -> Result<()> {
         repos_to_push.len()
     ));
 
+    let snapshot_repos: Vec<(String, std::path::PathBuf)> = repos_to_push
+        .iter()
+        .map(|repo| (repo.name.clone(), resolve_repo_path(&view_context.view_root, repo)))
+        .collect();
+    if let Err(e) = oplog::record_snapshot(&view_context.view_root, oplog::OperationKind::PushAll, &snapshot_repos) {
+        ui::print_warning(&format!("Failed to record undo snapshot: {e}"));
+    }
+
     // Second pass: push commits with failure handling
     for repo in &repos_to_push {
         let repo_name = &repo.name;
         let repo_path = resolve_repo_path(&view_context.view_root, repo);
 
         ui::print_info(&format!("Pushing commits in {repo_name}"));
-        match git::push(&repo_path) {
+        match backend.push_all(&repo_path) {
             Ok(()) => {
                 pushed_repos.push(repo_name.clone());
             }
@@ -527,6 +826,153 @@ fn workspace_push_all() -> Result<()> {
     Ok(())
 }
 
+/// A repo's state as of the last `watch` poll: branch/ahead/behind/dirty, or
+/// [`RepoWatchState::Degraded`] when the repo is missing or its status
+/// couldn't be read. Degraded repos are reported without aborting the poll
+/// cycle, same as `status`'s handling of missing repos.
+#[derive(Debug, Clone, PartialEq)]
+enum RepoWatchState {
+    Healthy {
+        branch: String,
+        ahead: u32,
+        behind: u32,
+        dirty: bool,
+    },
+    Degraded(String),
+}
+
+fn poll_repo_watch_state(
+    backend: &dyn crate::backend::GitBackend,
+    repo: &models::Repository,
+    view_root: &Path,
+) -> RepoWatchState {
+    let repo_path = resolve_repo_path(view_root, repo);
+    let repo_dir_name = repo_directory_name(repo);
+
+    if let Err(e) = git::validate_repository_directory(&repo_path, repo_dir_name) {
+        return RepoWatchState::Degraded(e.to_string());
+    }
+
+    match backend.status(&repo_path) {
+        Ok(status) => RepoWatchState::Healthy {
+            branch: status.branch,
+            ahead: status.ahead,
+            behind: status.behind,
+            dirty: status.dirty,
+        },
+        Err(e) => RepoWatchState::Degraded(e.to_string()),
+    }
+}
+
+/// Print what changed for `repo_name` between `previous` and `current`,
+/// printing nothing when nothing changed (so a quiet view stays quiet).
+fn report_watch_transition(
+    repo_name: &str,
+    previous: Option<&RepoWatchState>,
+    current: &RepoWatchState,
+) {
+    match (previous, current) {
+        (None, RepoWatchState::Degraded(e)) => {
+            ui::print_error(&format!("{repo_name}: degraded - {e}"));
+        }
+        (None, RepoWatchState::Healthy { branch, .. }) => {
+            ui::print_info(&format!("{repo_name}: watching ({branch})"));
+        }
+        (Some(RepoWatchState::Healthy { .. }), RepoWatchState::Degraded(e)) => {
+            ui::print_error(&format!("{repo_name}: became degraded - {e}"));
+        }
+        (Some(RepoWatchState::Degraded(_)), RepoWatchState::Healthy { .. }) => {
+            ui::print_success(&format!("{repo_name}: recovered"));
+        }
+        (Some(RepoWatchState::Degraded(_)), RepoWatchState::Degraded(_)) => {
+            // Still degraded - the initial "degraded" message already said so.
+        }
+        (
+            Some(RepoWatchState::Healthy {
+                branch: prev_branch,
+                ahead: prev_ahead,
+                behind: prev_behind,
+                dirty: prev_dirty,
+            }),
+            RepoWatchState::Healthy {
+                branch,
+                ahead,
+                behind,
+                dirty,
+            },
+        ) => {
+            if branch != prev_branch {
+                ui::print_info(&format!("{repo_name}: branch changed {prev_branch} -> {branch}"));
+            }
+            if behind > prev_behind {
+                ui::print_warning(&format!(
+                    "{repo_name}: fell behind ({prev_behind} -> {behind} commits behind upstream)"
+                ));
+            }
+            if ahead > prev_ahead {
+                ui::print_info(&format!(
+                    "{repo_name}: {} new local commit(s)",
+                    ahead - prev_ahead
+                ));
+            }
+            if *dirty && !prev_dirty {
+                ui::print_warning(&format!("{repo_name}: working tree became dirty"));
+            }
+            if !dirty && *prev_dirty {
+                ui::print_info(&format!("{repo_name}: working tree is clean again"));
+            }
+        }
+    }
+}
+
+/// Re-poll every repo in the current view every `interval_secs` seconds,
+/// printing only what changed since the last poll - new upstream commits,
+/// branches that fell behind, working trees that became dirty - so a
+/// developer can leave it running across a multi-repo view. Runs until
+/// interrupted (Ctrl-C); missing/corrupted repos are reported as degraded
+/// entries rather than aborting the poll cycle.
+fn watch(backend_kind: BackendKind, interval_secs: u64) -> Result<()> {
+    let backend = backend_kind.resolve();
+
+    let current_dir = std::env::current_dir()?;
+    let view_context =
+        load_view_context(&current_dir).with_context(|| "Failed to run 'viewyard watch'")?;
+
+    if view_context.active_repos.is_empty() {
+        ui::print_warning("No repositories in this view");
+        return Ok(());
+    }
+
+    ui::print_header(&format!(
+        "Watching {} repositories in view '{}' (polling every {interval_secs}s, Ctrl-C to stop)",
+        view_context.active_repos.len(),
+        view_context.view_name
+    ));
+
+    let worker_count = crate::parallel::default_worker_count(view_context.active_repos.len());
+    let mut previous: std::collections::HashMap<String, RepoWatchState> =
+        std::collections::HashMap::new();
+
+    loop {
+        let view_root = view_context.view_root.clone();
+        let results = crate::parallel::parallel_map(
+            view_context.active_repos.clone(),
+            worker_count,
+            |repo| {
+                let state = poll_repo_watch_state(backend.as_ref(), &repo, &view_root);
+                (repo.name.clone(), state)
+            },
+        );
+
+        for (repo_name, state) in results {
+            report_watch_transition(&repo_name, previous.get(&repo_name), &state);
+            previous.insert(repo_name, state);
+        }
+
+        std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+    }
+}
+
 // Helper functions
 
 #[derive(Debug)]
@@ -538,10 +984,9 @@ struct ViewContext {
 }
 
 fn load_view_context(current_dir: &Path) -> Result<ViewContext> {
-    // Check if current directory is a view (parent contains .viewyard-repos.json)
+    // Check if current directory is a view (parent contains a repos file)
     if let Some(parent) = current_dir.parent() {
-        let repos_file = parent.join(".viewyard-repos.json");
-        if repos_file.exists() {
+        if let Some((repos_file, format)) = repos_file::find_repos_file(parent) {
             let viewset_name = parent
                 .file_name()
                 .and_then(|n| n.to_str())
@@ -557,9 +1002,12 @@ fn load_view_context(current_dir: &Path) -> Result<ViewContext> {
             let active_repos = load_and_validate_repos(&repos_file).unwrap_or_else(|e| {
                 ui::print_error(&format!("Configuration validation failed: {e}"));
                 ui::print_info("To fix this:");
-                ui::print_info("   • Check the JSON syntax in .viewyard-repos.json");
+                ui::print_info(&format!("   • Check the {} syntax in {}", match format {
+                    repos_file::RepoFileFormat::Json => "JSON",
+                    repos_file::RepoFileFormat::Toml => "TOML",
+                }, format.filename()));
                 ui::print_info("   • Ensure all repositories have 'name' and 'url' fields");
-                ui::print_info("   • Use 'cat .viewyard-repos.json' to inspect the file");
+                ui::print_info(&format!("   • Use 'cat {}' to inspect the file", format.filename()));
                 Vec::new()
             });
 
@@ -587,36 +1035,60 @@ fn load_view_context(current_dir: &Path) -> Result<ViewContext> {
             "Expected structure: <viewset>/<view>/",
             "Example: cd my-project/feature-123",
             "Create a view: viewyard view create feature-123",
-            "List viewsets: find . -maxdepth 2 -name '.viewyard-repos.json' -exec dirname {} \\;",
+            "List viewsets: find . -maxdepth 2 -name '.viewyard-repos.*' -exec dirname {} \\;",
         ],
     );
     anyhow::bail!("Not in a view directory")
 }
 
-fn get_repo_status(repo_path: &Path, repo_name: &str) -> Result<Option<String>> {
-    // Get current branch
-    let branch = git::get_current_branch(repo_path)
-        .with_context(|| format!("Failed to get current branch for repository '{repo_name}'"))?;
-
-    // Check for uncommitted changes
-    let has_changes = git::has_uncommitted_changes(repo_path)?;
-
-    // Check for unpushed commits
-    let has_unpushed = git::has_unpushed_commits(repo_path)?;
-
-    // Check for stashes
-    let stash_count = git::get_stash_count(repo_path)?;
+/// Report a single repo's status line plus the [`git::StatusSummary`] it was
+/// derived from (for the caller to tally into a view-wide breakdown), or
+/// `None` if the repo is completely clean.
+///
+/// The backend (shell/gitoxide/mock) supplies the coarse branch/dirty/
+/// ahead/behind picture; when the repo is on disk, that's layered over with
+/// the finer-grained `git status --porcelain=v2 --branch` breakdown (staged
+/// vs. unstaged, untracked, conflicted) so the summary can distinguish those
+/// states. Tests drive this purely through the backend, so the porcelain
+/// layer is best-effort and silently skipped when it fails (e.g. the path
+/// doesn't actually exist on disk).
+fn get_repo_status(
+    backend: &dyn crate::backend::GitBackend,
+    repo_path: &Path,
+    repo_name: &str,
+) -> Result<Option<(String, git::StatusSummary)>> {
+    // Get status through the selected backend (shell or gitoxide)
+    let backend_status = backend
+        .status(repo_path)
+        .with_context(|| format!("Failed to get status for repository '{repo_name}'"))?;
+    let branch = backend_status.branch;
+    let stash_count = backend.get_stash_count(repo_path)?;
+
+    let mut summary = git::StatusSummary {
+        ahead: backend_status.ahead as usize,
+        behind: backend_status.behind as usize,
+        unstaged_modified: backend_status.dirty,
+        stashed: stash_count > 0,
+        ..git::StatusSummary::default()
+    };
+    if let Ok(detailed) = git::status_summary(repo_path) {
+        summary = git::StatusSummary {
+            ahead: summary.ahead,
+            behind: summary.behind,
+            stashed: summary.stashed,
+            ..detailed
+        };
+    }
 
     // Skip completely clean repos
-    if !has_changes && !has_unpushed && stash_count == 0 {
+    if summary.is_clean() {
         return Ok(None);
     }
 
     // Build concise one-line status
     let mut status_parts = Vec::new();
 
-    if has_changes {
-        // Count changes
+    if summary.unstaged_modified || summary.staged_added || summary.staged_renamed || summary.staged_deleted {
         match git::get_status(repo_path) {
             Ok(status_output) => {
                 let change_count = status_output.lines().count();
@@ -630,83 +1102,155 @@ fn get_repo_status(repo_path: &Path, repo_name: &str) -> Result<Option<String>>
         }
     }
 
-    if has_unpushed {
-        match git::run_git_command_string(&["rev-list", "--count", "@{u}..HEAD"], Some(repo_path)) {
-            Ok(count_str) => {
-                if let Ok(count) = count_str.parse::<u32>() {
-                    if count > 0 {
-                        status_parts.push(format!("{count} commits ahead"));
-                    }
-                }
-            }
-            Err(_) => {
-                status_parts.push("commits ahead".to_string());
-            }
-        }
+    if summary.ahead > 0 {
+        status_parts.push(format!("{} commits ahead", summary.ahead));
+    }
+
+    if summary.behind > 0 {
+        status_parts.push(format!("{} commits behind", summary.behind));
+    }
+
+    if summary.unmerged {
+        status_parts.push("conflicted".to_string());
+    }
+
+    if summary.untracked {
+        status_parts.push("untracked files".to_string());
     }
 
     if stash_count > 0 {
         status_parts.push(format!("{stash_count} stashes"));
     }
 
-    let status_summary = if status_parts.is_empty() {
+    let status_summary_text = if status_parts.is_empty() {
         "clean".to_string()
     } else {
         status_parts.join(", ")
     };
 
-    let icon = if has_changes { "!" } else { "→" };
+    let icon = if summary.unmerged {
+        "✘"
+    } else if summary.unstaged_modified || summary.staged_added || summary.staged_renamed || summary.staged_deleted {
+        "!"
+    } else {
+        "→"
+    };
 
-    Ok(Some(format!(
-        "{icon} {repo_name} ({branch}) - {status_summary}"
+    Ok(Some((
+        format!("{icon} {repo_name} ({branch}) - {status_summary_text}"),
+        summary,
     )))
 }
 
-fn check_branch_consistency(repo_branches: &[(String, String)]) {
+/// Build the `--format json` record for one repo from its [`git::StatusSummary`],
+/// for [`workspace_status`] - the structured counterpart to the prose line
+/// `get_repo_status` builds for `--format text`.
+fn repo_status_from_summary(name: String, branch: String, summary: &git::StatusSummary) -> models::RepoStatus {
+    models::RepoStatus {
+        name,
+        branch,
+        ahead: summary.ahead,
+        behind: summary.behind,
+        dirty: summary.unstaged_modified || summary.staged_added || summary.staged_renamed || summary.staged_deleted,
+        untracked: summary.untracked,
+        conflicted: summary.unmerged,
+        stashed: summary.stashed,
+    }
+}
+
+/// Render a Unix timestamp as a short relative age, e.g. "2h ago" or "3d ago".
+fn format_relative_age(timestamp: i64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(timestamp);
+    let age_secs = (now - timestamp).max(0);
+
+    if age_secs < 60 {
+        "just now".to_string()
+    } else if age_secs < 3600 {
+        format!("{}m ago", age_secs / 60)
+    } else if age_secs < 86400 {
+        format!("{}h ago", age_secs / 3600)
+    } else if age_secs < 86400 * 30 {
+        format!("{}d ago", age_secs / 86400)
+    } else {
+        format!("{}mo ago", age_secs / (86400 * 30))
+    }
+}
+
+fn check_branch_consistency(repo_branches: &[(String, String, Option<i64>)]) {
     if repo_branches.len() <= 1 {
         return;
     }
 
-    // Group repos by branch
-    let mut branch_groups: std::collections::HashMap<String, Vec<String>> =
+    // Group repos by branch, tracking the most recent HEAD timestamp seen
+    // for each branch so the listing below can be ordered by recency.
+    let mut branch_groups: std::collections::HashMap<String, (Vec<String>, Option<i64>)> =
         std::collections::HashMap::new();
-    for (repo, branch) in repo_branches {
-        branch_groups
-            .entry(branch.clone())
-            .or_default()
-            .push(repo.clone());
+    for (repo, branch, timestamp) in repo_branches {
+        let entry = branch_groups.entry(branch.clone()).or_default();
+        entry.0.push(repo.clone());
+        entry.1 = match (entry.1, timestamp) {
+            (Some(existing), Some(candidate)) => Some(existing.max(*candidate)),
+            (existing, None) => existing,
+            (None, Some(candidate)) => Some(*candidate),
+        };
     }
 
     if branch_groups.len() > 1 {
         ui::print_warning("Branch mismatch detected:");
-        for (branch, repos) in &branch_groups {
+
+        // Most recently updated branches first; branches with no readable
+        // HEAD timestamp sort to the end.
+        let mut groups: Vec<_> = branch_groups.into_iter().collect();
+        groups.sort_by(|(_, (_, a)), (_, (_, b))| b.cmp(a));
+
+        for (branch, (repos, timestamp)) in &groups {
+            let age = timestamp
+                .map(|ts| format!(" (updated {})", format_relative_age(ts)))
+                .unwrap_or_default();
             if repos.len() == 1 {
-                ui::print_warning(&format!("  - {}: {}", repos[0], branch));
+                ui::print_warning(&format!("  - {}: {branch}{age}", repos[0]));
             } else {
-                ui::print_info(&format!("  - {} repos on: {}", repos.len(), branch));
+                ui::print_info(&format!("  - {} repos on: {branch}{age}", repos.len()));
             }
         }
         println!();
     }
 }
 
-fn show_status_summary(
-    clean_count: usize,
-    dirty_count: usize,
-    ahead_count: usize,
-    repo_branches: &[(String, String)],
-) {
-    let total = clean_count + dirty_count;
+/// Render the view-wide summary line, e.g. `5 repos: 1 conflicted, 1
+/// diverged, 2 dirty, 1 ahead, 1 behind, 2 untracked, 1 stashed, 2 clean |
+/// All on feature-123 ✓`. Conflicted and diverged are listed first since
+/// they're the states that block the synchronized-branch workflow.
+fn show_status_summary(counts: &StatusCounts, repo_branches: &[(String, String, Option<i64>)]) {
+    let total = repo_branches.len();
     let mut summary_parts = Vec::new();
 
-    if clean_count > 0 {
-        summary_parts.push(format!("{clean_count} clean"));
+    if counts.conflicted > 0 {
+        summary_parts.push(format!("{} conflicted", counts.conflicted));
+    }
+    if counts.diverged > 0 {
+        summary_parts.push(format!("{} diverged", counts.diverged));
+    }
+    if counts.dirty > 0 {
+        summary_parts.push(format!("{} dirty", counts.dirty));
+    }
+    if counts.ahead > 0 {
+        summary_parts.push(format!("{} ahead", counts.ahead));
     }
-    if dirty_count > 0 {
-        summary_parts.push(format!("{dirty_count} dirty"));
+    if counts.behind > 0 {
+        summary_parts.push(format!("{} behind", counts.behind));
     }
-    if ahead_count > 0 {
-        summary_parts.push(format!("{ahead_count} ahead"));
+    if counts.untracked > 0 {
+        summary_parts.push(format!("{} untracked", counts.untracked));
+    }
+    if counts.stashed > 0 {
+        summary_parts.push(format!("{} stashed", counts.stashed));
+    }
+    if counts.clean > 0 {
+        summary_parts.push(format!("{} clean", counts.clean));
     }
 
     let status_summary = if summary_parts.is_empty() {
@@ -722,7 +1266,7 @@ fn show_status_summary(
         let first_branch = &repo_branches[0].1;
         if repo_branches
             .iter()
-            .all(|(_, branch)| branch == first_branch)
+            .all(|(_, branch, _)| branch == first_branch)
         {
             format!(" | All on {first_branch} ✓")
         } else {
@@ -735,40 +1279,109 @@ fn show_status_summary(
     ));
 }
 
-fn validate_branch_synchronization(view_context: &ViewContext) -> Result<()> {
-    let mut branches = std::collections::HashMap::new();
-    let mut errors = Vec::new();
-
-    // Check branch for each repository
-    for repo in &view_context.active_repos {
-        let repo_path = resolve_repo_path(&view_context.view_root, repo);
-        let dir_name = repo_directory_name(repo);
+/// Walk `view_root` for top-level directories containing a `.git` entry that
+/// don't correspond to any repo in `managed_repos` (by expected directory
+/// name, via [`repo_directory_name`]), and warn about them. The manifest is
+/// authoritative for what belongs in a view, so stray clones or repos that
+/// were removed from it but left on disk should be visible rather than
+/// silently ignored.
+fn report_unmanaged_repos(view_root: &Path, managed_repos: &[models::Repository]) {
+    let managed_dir_names: std::collections::HashSet<&str> =
+        managed_repos.iter().map(repo_directory_name).collect();
+
+    let Ok(entries) = std::fs::read_dir(view_root) else {
+        return;
+    };
 
-        if !repo_path.exists() {
-            errors.push(format!(
-                "Repository '{}' directory '{}' not found",
-                repo.name, dir_name
-            ));
+    let mut unmanaged = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() || !git::is_git_repo(&path) {
             continue;
         }
-
-        if !git::is_git_repo(&repo_path) {
-            errors.push(format!(
-                "'{}' (directory '{}') is not a git repository",
-                repo.name, dir_name
-            ));
+        let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) else {
             continue;
+        };
+        if !managed_dir_names.contains(dir_name) {
+            unmanaged.push(dir_name.to_string());
         }
+    }
 
-        match git::get_current_branch(&repo_path) {
-            Ok(branch) => {
-                branches.insert(repo.name.clone(), branch);
+    if unmanaged.is_empty() {
+        return;
+    }
+
+    unmanaged.sort();
+    ui::print_warning(&format!(
+        "Found {} unmanaged git repositor{} under this view that {} not part of the view definition",
+        unmanaged.len(),
+        if unmanaged.len() == 1 { "y" } else { "ies" },
+        if unmanaged.len() == 1 { "is" } else { "are" }
+    ));
+    for dir_name in &unmanaged {
+        ui::print_info(&format!("  • {dir_name}"));
+    }
+}
+
+/// Fetch every active repo's current branch concurrently (each subprocess
+/// spawn is independent, so only the final aggregation is synchronized),
+/// returning `(repo_name -> branch)` plus any per-repo errors, sorted by
+/// repo name so reporting stays deterministic.
+///
+/// Each repo's VCS is detected via [`crate::vcs::resolve_vcs_backend`] (by
+/// probing for `.git` vs `.hg`), so a view mixing Git and Mercurial repos
+/// can still be checked for a consistent branch/bookmark name; an
+/// unrecognized VCS is reported as a per-repo error rather than panicking.
+fn collect_branches(
+    view_context: &ViewContext,
+) -> (std::collections::HashMap<String, String>, Vec<String>) {
+    let worker_count = crate::parallel::default_worker_count(view_context.active_repos.len());
+    let view_root = view_context.view_root.clone();
+    let mut checks: Vec<(String, std::result::Result<String, String>)> = crate::parallel::parallel_map(
+        view_context.active_repos.clone(),
+        worker_count,
+        |repo| {
+            let repo_path = resolve_repo_path(&view_root, &repo);
+            let dir_name = repo_directory_name(&repo).to_string();
+
+            if !repo_path.exists() {
+                return (
+                    repo.name.clone(),
+                    Err(format!(
+                        "Repository '{}' directory '{}' not found",
+                        repo.name, dir_name
+                    )),
+                );
             }
-            Err(e) => {
-                errors.push(format!("Failed to get branch for '{}': {}", repo.name, e));
+
+            let vcs = match crate::vcs::resolve_vcs_backend(&repo_path) {
+                Ok(vcs) => vcs,
+                Err(e) => return (repo.name.clone(), Err(format!("'{}' (directory '{}'): {}", repo.name, dir_name, e))),
+            };
+
+            let result = vcs
+                .current_branch(&repo_path)
+                .map_err(|e| format!("Failed to get branch for '{}': {}", repo.name, e));
+            (repo.name.clone(), result)
+        },
+    );
+    checks.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut branches = std::collections::HashMap::new();
+    let mut errors = Vec::new();
+    for (repo_name, result) in checks {
+        match result {
+            Ok(branch) => {
+                branches.insert(repo_name, branch);
             }
+            Err(e) => errors.push(e),
         }
     }
+    (branches, errors)
+}
+
+fn validate_branch_synchronization(view_context: &ViewContext) -> Result<()> {
+    let (branches, errors) = collect_branches(view_context);
 
     // Report any errors
     if !errors.is_empty() {
@@ -819,3 +1432,182 @@ fn validate_branch_synchronization(view_context: &ViewContext) -> Result<()> {
     ));
     Ok(())
 }
+
+/// Outcome of attempting to check out the view's branch in a single repo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BranchFixOutcome {
+    Fixed,
+    SkippedDirty,
+    Failed(String),
+}
+
+/// `--fix` counterpart to [`validate_branch_synchronization`]: for every
+/// repo not already on `view_context.view_name`, check out that branch
+/// (creating it from the default branch if it doesn't exist locally yet) —
+/// but only when the repo's working tree is clean, since checking out over
+/// uncommitted changes risks losing or silently carrying them onto the new
+/// branch. Reports a fixed/skipped/failed summary rather than bailing.
+fn fix_branch_synchronization(view_context: &ViewContext, backend: &dyn crate::backend::GitBackend, json_format: bool) {
+    let (branches, errors) = collect_branches(view_context);
+    if !json_format {
+        for error in &errors {
+            ui::print_warning(&format!("{error}"));
+        }
+    }
+
+    let expected_branch = &view_context.view_name;
+    let repo_by_name: std::collections::HashMap<&str, &models::Repository> = view_context
+        .active_repos
+        .iter()
+        .map(|r| (r.name.as_str(), r))
+        .collect();
+
+    let mismatched: Vec<(&str, &str)> = branches
+        .iter()
+        .filter(|(_, branch)| branch.as_str() != expected_branch)
+        .map(|(name, branch)| (name.as_str(), branch.as_str()))
+        .collect();
+
+    if mismatched.is_empty() {
+        if !json_format {
+            ui::print_info(&format!(
+                "✓ All repositories are synchronized on branch '{expected_branch}'"
+            ));
+        }
+        return;
+    }
+
+    let mut outcomes: Vec<(String, BranchFixOutcome)> = mismatched
+        .iter()
+        .map(|(repo_name, actual_branch)| {
+            let Some(repo) = repo_by_name.get(*repo_name) else {
+                return (
+                    (*repo_name).to_string(),
+                    BranchFixOutcome::Failed("repo no longer in view".to_string()),
+                );
+            };
+            let repo_path = resolve_repo_path(&view_context.view_root, repo);
+
+            match backend.has_uncommitted_changes(&repo_path) {
+                Ok(true) => {
+                    if !json_format {
+                        ui::print_warning(&format!(
+                            "{repo_name}: on '{actual_branch}' but has uncommitted changes; skipping checkout to '{expected_branch}'"
+                        ));
+                    }
+                    ((*repo_name).to_string(), BranchFixOutcome::SkippedDirty)
+                }
+                Ok(false) => match git::checkout_branch(&repo_path, expected_branch) {
+                    Ok(()) => {
+                        if !json_format {
+                            ui::print_success(&format!(
+                                "{repo_name}: checked out '{expected_branch}' (was on '{actual_branch}')"
+                            ));
+                        }
+                        ((*repo_name).to_string(), BranchFixOutcome::Fixed)
+                    }
+                    Err(e) => {
+                        if !json_format {
+                            ui::print_warning(&format!("{repo_name}: failed to check out '{expected_branch}': {e}"));
+                        }
+                        ((*repo_name).to_string(), BranchFixOutcome::Failed(e.to_string()))
+                    }
+                },
+                Err(e) => {
+                    if !json_format {
+                        ui::print_warning(&format!("{repo_name}: failed to check for uncommitted changes: {e}"));
+                    }
+                    ((*repo_name).to_string(), BranchFixOutcome::Failed(e.to_string()))
+                }
+            }
+        })
+        .collect();
+    outcomes.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let fixed = outcomes.iter().filter(|(_, o)| *o == BranchFixOutcome::Fixed).count();
+    let skipped = outcomes.iter().filter(|(_, o)| *o == BranchFixOutcome::SkippedDirty).count();
+    let failed = outcomes.iter().filter(|(_, o)| matches!(o, BranchFixOutcome::Failed(_))).count();
+
+    if !json_format {
+        ui::print_info(&format!(
+            "Branch fix summary: {fixed} fixed, {skipped} skipped (dirty), {failed} failed"
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::mock::MockBackend;
+    use crate::backend::RepoStatus;
+
+    fn repo(name: &str) -> models::Repository {
+        models::Repository {
+            name: name.to_string(),
+            url: format!("git@github.com:acme/{name}.git"),
+            is_private: false,
+            source: "GitHub (acme)".to_string(),
+            account: None,
+            category: None,
+            backend: None,
+            topics: Vec::new(),
+            language: None,
+            clone_strategy: None,
+        }
+    }
+
+    #[test]
+    fn test_get_repo_status_reports_clean_as_none() {
+        let path = Path::new("/nonexistent/clean-repo");
+        let backend = MockBackend::default()
+            .with_status(
+                path,
+                RepoStatus {
+                    branch: "main".to_string(),
+                    dirty: false,
+                    ahead: 0,
+                    behind: 0,
+                },
+            )
+            .with_stash_count(path, 0);
+
+        let status = get_repo_status(&backend, path, "clean-repo").unwrap();
+        assert_eq!(status, None);
+    }
+
+    #[test]
+    fn test_get_repo_status_summarizes_ahead_and_stashed() {
+        let path = Path::new("/nonexistent/busy-repo");
+        let backend = MockBackend::default()
+            .with_status(
+                path,
+                RepoStatus {
+                    branch: "feature".to_string(),
+                    dirty: false,
+                    ahead: 2,
+                    behind: 0,
+                },
+            )
+            .with_stash_count(path, 1);
+
+        let (status, summary) = get_repo_status(&backend, path, "busy-repo").unwrap().unwrap();
+        assert!(status.contains("2 commits ahead"));
+        assert!(status.contains("1 stashes"));
+        assert_eq!(summary.ahead, 2);
+        assert!(summary.stashed);
+    }
+
+    #[test]
+    fn test_validate_branch_synchronization_errors_for_missing_repo_dirs() {
+        let view_context = ViewContext {
+            viewset_name: "acme".to_string(),
+            view_root: Path::new("/nonexistent").to_path_buf(),
+            view_name: "feature".to_string(),
+            active_repos: vec![repo("a"), repo("b")],
+        };
+        // Neither repo exists on disk, so this should report both as missing
+        // before ever probing for a VCS to consult.
+        let result = validate_branch_synchronization(&view_context);
+        assert!(result.is_err());
+    }
+}
@@ -1,44 +1,269 @@
 use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::models::ViewsetsConfig;
 
-/// Get the viewyard configuration directory
+/// Get the viewyard configuration directory, resolved the way
+/// `directories::ProjectDirs` would: `$XDG_CONFIG_HOME` first on any
+/// platform, then the conventional per-OS location - `$HOME/.config` on
+/// Linux, `$HOME/Library/Application Support` on macOS, `%APPDATA%` on
+/// Windows.
 pub fn config_dir() -> Result<PathBuf> {
+    if let Some(xdg) = std::env::var_os("XDG_CONFIG_HOME") {
+        if !xdg.is_empty() {
+            return Ok(PathBuf::from(xdg).join("viewyard"));
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        if let Some(appdata) = std::env::var_os("APPDATA") {
+            if !appdata.is_empty() {
+                return Ok(PathBuf::from(appdata).join("viewyard"));
+            }
+        }
+    }
+
     let home = std::env::var("HOME").context("HOME environment variable not set")?;
-    Ok(PathBuf::from(home).join(".config").join("viewyard"))
+    let home = PathBuf::from(home);
+
+    #[cfg(target_os = "macos")]
+    {
+        Ok(home.join("Library").join("Application Support").join("viewyard"))
+    }
+
+    #[cfg(windows)]
+    {
+        Ok(home.join("AppData").join("Roaming").join("viewyard"))
+    }
+
+    #[cfg(not(any(target_os = "macos", windows)))]
+    {
+        Ok(home.join(".config").join("viewyard"))
+    }
 }
 
-/// Get the path to the viewsets configuration file
+/// The pre-cross-platform config location (`$HOME/.config/viewyard`),
+/// checked as a fallback so configs written before [`config_dir`] learned
+/// about `$XDG_CONFIG_HOME`/macOS/Windows keep being found without requiring
+/// users to move the file.
+fn legacy_config_dir() -> Option<PathBuf> {
+    std::env::var("HOME")
+        .ok()
+        .map(|home| PathBuf::from(home).join(".config").join("viewyard"))
+}
+
+/// Every directory viewyard's config might live in, in priority order: the
+/// platform-appropriate [`config_dir`] first, then [`legacy_config_dir`] as
+/// a backward-compatibility fallback.
+fn config_dir_probe_list() -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Ok(primary) = config_dir() {
+        candidates.push(primary);
+    }
+    if let Some(legacy) = legacy_config_dir() {
+        if !candidates.contains(&legacy) {
+            candidates.push(legacy);
+        }
+    }
+    candidates
+}
+
+/// Get the path to the viewsets configuration file: whichever candidate in
+/// [`config_dir_probe_list`] already has a `viewsets.yaml`, falling back to
+/// the canonical [`config_dir`] location (used for new writes) if none do.
 pub fn viewsets_config_path() -> Result<PathBuf> {
+    for dir in config_dir_probe_list() {
+        let candidate = dir.join("viewsets.yaml");
+        if candidate.exists() {
+            return Ok(candidate);
+        }
+    }
+
     Ok(config_dir()?.join("viewsets.yaml"))
 }
 
-/// Load viewsets configuration from ~/.config/viewyard/viewsets.yaml
+/// Load viewsets configuration from the file [`viewsets_config_path`] finds,
+/// seeding a minimal empty config via [`ensure_viewsets_config`] if none
+/// exists yet rather than forcing users through `onboard` first.
 pub fn load_viewsets_config() -> Result<ViewsetsConfig> {
-    let config_path = viewsets_config_path()?;
-    
-    if !config_path.exists() {
-        anyhow::bail!(
-            "Viewsets configuration not found at {}\nRun 'viewyard onboard' to set up your configuration",
-            config_path.display()
-        );
-    }
+    let config_path = ensure_viewsets_config()?;
 
     let content = fs::read_to_string(&config_path)
         .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
 
-    let config: ViewsetsConfig = serde_yaml::from_str(&content)
+    let mut config: ViewsetsConfig = serde_yaml::from_str(&content)
         .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
 
+    merge_viewset_fragments(&mut config, &config_path)?;
+    validate_viewsets_config(&config)?;
+
     Ok(config)
 }
 
+/// A single problem found by [`validate_viewsets_config`]. Collected into a
+/// `Vec` rather than returned individually so every problem in a hand-edited
+/// `viewsets.yaml` is reported at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ConfigValidationIssue {
+    EmptyViewsetName,
+    EmptyRepoName { viewset: String },
+    DuplicateRepoName { viewset: String, repo: String },
+}
+
+impl fmt::Display for ConfigValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyViewsetName => write!(f, "a viewset name is empty or whitespace-only"),
+            Self::EmptyRepoName { viewset } => {
+                write!(f, "viewset '{viewset}' has a repo with an empty or whitespace-only name")
+            }
+            Self::DuplicateRepoName { viewset, repo } => {
+                write!(f, "viewset '{viewset}' lists repo '{repo}' more than once")
+            }
+        }
+    }
+}
+
+/// Validate a loaded (or about-to-be-saved) [`ViewsetsConfig`], collecting
+/// every problem found rather than failing on the first serde error, so
+/// hand-editing `viewsets.yaml` gets actionable feedback. Checks for
+/// empty/whitespace viewset or repo names and repo names duplicated within a
+/// single viewset (views themselves aren't tracked in this struct - they're
+/// directories created under the viewset's workspace root - so a duplicated
+/// repo name, which would collide within every view's checkout, is the
+/// structural analog worth catching here). Also warns, without failing, about
+/// any viewset whose workspace root (resolved from `config` the same way
+/// [`get_viewset_root`] resolves it) doesn't exist on disk yet.
+pub fn validate_viewsets_config(config: &ViewsetsConfig) -> Result<()> {
+    let mut issues = Vec::new();
+
+    for (viewset_name, viewset) in &config.viewsets {
+        if viewset_name.trim().is_empty() {
+            issues.push(ConfigValidationIssue::EmptyViewsetName);
+        }
+
+        let mut seen_repo_names = HashSet::new();
+        for repo in &viewset.repos {
+            if repo.name.trim().is_empty() {
+                issues.push(ConfigValidationIssue::EmptyRepoName {
+                    viewset: viewset_name.clone(),
+                });
+            } else if !seen_repo_names.insert(repo.name.as_str()) {
+                issues.push(ConfigValidationIssue::DuplicateRepoName {
+                    viewset: viewset_name.clone(),
+                    repo: repo.name.clone(),
+                });
+            }
+        }
+
+        let template = root_template_for(viewset_name, Some(config));
+        if let Ok(root) = expand_root_template(&template, viewset_name) {
+            if !root.exists() {
+                crate::ui::print_warning(&format!(
+                    "Viewset '{viewset_name}' workspace root does not exist yet: {}",
+                    root.display()
+                ));
+            }
+        }
+    }
+
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    let config_path = viewsets_config_path()
+        .map(|path| path.display().to_string())
+        .unwrap_or_else(|_| "<unknown>".to_string());
+    let details: Vec<String> = issues.iter().map(|issue| format!("  - {issue}")).collect();
+    anyhow::bail!(
+        "Invalid viewsets configuration at {config_path}:\n{}",
+        details.join("\n")
+    );
+}
+
+/// How to resolve two fragments (or the main config and a fragment) defining
+/// the same viewset name with different content. The default refuses to
+/// merge silently-divergent definitions; `Override` keeps whichever fragment
+/// was read last, matching the "later files win" precedence used when
+/// there's no conflict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FragmentConflictMode {
+    Error,
+    Override,
+}
+
+impl FragmentConflictMode {
+    /// Read from `VIEWYARD_FRAGMENT_CONFLICT_MODE` (`"error"` or
+    /// `"override"`), defaulting to `Error` when unset or unrecognized.
+    fn from_env() -> Self {
+        match std::env::var("VIEWYARD_FRAGMENT_CONFLICT_MODE").as_deref() {
+            Ok("override") => Self::Override,
+            _ => Self::Error,
+        }
+    }
+}
+
+/// Glob `viewsets.d/*.yaml` next to `main_config_path` in sorted order and
+/// deep-merge each fragment's `viewsets` map into `config`, so per-team or
+/// per-project viewset definitions can live in separate files instead of one
+/// monolithic `viewsets.yaml`. Later fragments override earlier keys unless
+/// they disagree on content, in which case [`FragmentConflictMode`] decides
+/// whether that's an error or a silent override.
+fn merge_viewset_fragments(config: &mut ViewsetsConfig, main_config_path: &std::path::Path) -> Result<()> {
+    let Some(config_dir) = main_config_path.parent() else {
+        return Ok(());
+    };
+    let fragments_dir = config_dir.join("viewsets.d");
+    if !fragments_dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut fragment_paths: Vec<PathBuf> = fs::read_dir(&fragments_dir)
+        .with_context(|| format!("Failed to read {}", fragments_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("yaml"))
+        .collect();
+    fragment_paths.sort();
+
+    let conflict_mode = FragmentConflictMode::from_env();
+
+    for fragment_path in fragment_paths {
+        let content = fs::read_to_string(&fragment_path)
+            .with_context(|| format!("Failed to read config fragment: {}", fragment_path.display()))?;
+        let fragment: ViewsetsConfig = serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse config fragment: {}", fragment_path.display()))?;
+
+        for (name, viewset) in fragment.viewsets {
+            match config.viewsets.get(&name) {
+                Some(existing) if existing != &viewset && conflict_mode == FragmentConflictMode::Error => {
+                    anyhow::bail!(
+                        "Viewset '{}' is defined differently in {} than in an earlier config file - \
+                        set VIEWYARD_FRAGMENT_CONFLICT_MODE=override to keep the later definition",
+                        name,
+                        fragment_path.display()
+                    );
+                }
+                _ => {
+                    config.viewsets.insert(name, viewset);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Save viewsets configuration to ~/.config/viewyard/viewsets.yaml
 pub fn save_viewsets_config(config: &ViewsetsConfig) -> Result<()> {
+    validate_viewsets_config(config)?;
+
     let config_path = viewsets_config_path()?;
-    
+
     // Create config directory if it doesn't exist
     if let Some(parent) = config_path.parent() {
         fs::create_dir_all(parent)
@@ -61,10 +286,102 @@ pub fn config_exists() -> bool {
         .unwrap_or(false)
 }
 
-/// Get the workspace root directory for a viewset
+/// Ensure a viewsets config file exists on disk, returning its path.
+/// Mirrors jj's "create it if missing" config behavior: if no candidate in
+/// [`config_dir_probe_list`] has one yet, create parent directories and
+/// write a minimal valid [`ViewsetsConfig::default`] at the canonical
+/// [`config_dir`] location, so callers (and hand-editing) don't need
+/// `onboard` to have run first.
+pub fn ensure_viewsets_config() -> Result<PathBuf> {
+    if let Ok(path) = viewsets_config_path() {
+        if path.exists() {
+            return Ok(path);
+        }
+    }
+
+    let path = config_dir()?.join("viewsets.yaml");
+    save_viewsets_config(&ViewsetsConfig::default())?;
+    Ok(path)
+}
+
+/// Open the viewsets config file in `$EDITOR` (falling back to `vi`),
+/// creating it first via [`ensure_viewsets_config`] if it doesn't exist yet.
+pub fn edit_viewsets_config() -> Result<()> {
+    let path = ensure_viewsets_config()?;
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+
+    let status = std::process::Command::new(&editor)
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to launch editor '{editor}'"))?;
+
+    if !status.success() {
+        anyhow::bail!("Editor '{editor}' exited with a non-zero status");
+    }
+
+    // Re-parse so a hand-edit with a typo surfaces immediately rather than
+    // on the next unrelated command.
+    load_viewsets_config().map(|_| ())
+}
+
+/// Default template used when neither [`Viewset::workspace_root`] nor
+/// [`ViewsetsConfig::root_template`] specify one, matching viewyard's
+/// historical hardcoded layout.
+const DEFAULT_ROOT_TEMPLATE: &str = "~/src/src-{viewset}";
+
+/// Expand a `{viewset}`-templated path (e.g. `~/work/{viewset}` or an
+/// absolute path) into a concrete [`PathBuf`], substituting `~/` with `$HOME`.
+fn expand_root_template(template: &str, viewset_name: &str) -> Result<PathBuf> {
+    let expanded = template.replace("{viewset}", viewset_name);
+
+    if let Some(rest) = expanded.strip_prefix("~/") {
+        let home = std::env::var("HOME").context("HOME environment variable not set")?;
+        Ok(PathBuf::from(home).join(rest))
+    } else {
+        Ok(PathBuf::from(expanded))
+    }
+}
+
+/// Resolve the template that governs `viewset_name`'s workspace root:
+/// [`Viewset::workspace_root`] if set, else [`ViewsetsConfig::root_template`],
+/// else [`DEFAULT_ROOT_TEMPLATE`].
+fn root_template_for(viewset_name: &str, config: Option<&ViewsetsConfig>) -> String {
+    config
+        .and_then(|c| c.viewsets.get(viewset_name))
+        .and_then(|v| v.workspace_root.clone())
+        .or_else(|| config.and_then(|c| c.root_template.clone()))
+        .unwrap_or_else(|| DEFAULT_ROOT_TEMPLATE.to_string())
+}
+
+/// Get the workspace root directory for a viewset, honoring a per-viewset or
+/// config-wide root template when configured (see [`root_template_for`]).
+/// Falls back to [`DEFAULT_ROOT_TEMPLATE`] when no config can be loaded at
+/// all (e.g. before `onboard` has run), rather than failing outright.
 pub fn get_viewset_root(viewset_name: &str) -> Result<PathBuf> {
-    let home = std::env::var("HOME").context("HOME environment variable not set")?;
-    Ok(PathBuf::from(home).join("src").join(format!("src-{}", viewset_name)))
+    let config = load_viewsets_config().ok();
+    let template = root_template_for(viewset_name, config.as_ref());
+    expand_root_template(&template, viewset_name)
+}
+
+/// Resolve the [`crate::credentials::AuthMethod`] pinned for the viewset
+/// rooted at `viewset_root`, by matching it against [`get_viewset_root`] for
+/// every entry in `~/.config/viewyard/viewsets.yaml`. Falls back to
+/// [`crate::credentials::AuthMethod::Auto`] when no config can be loaded, or
+/// no entry's root matches - e.g. a viewset directory created directly via
+/// `viewset create` rather than registered in the YAML config.
+#[must_use]
+pub fn auth_method_for_viewset_root(viewset_root: &Path) -> crate::credentials::AuthMethod {
+    let Ok(config) = load_viewsets_config() else {
+        return crate::credentials::AuthMethod::default();
+    };
+
+    for (name, viewset) in &config.viewsets {
+        if get_viewset_root(name).is_ok_and(|root| root == viewset_root) {
+            return viewset.preferred_auth_method;
+        }
+    }
+
+    crate::credentials::AuthMethod::default()
 }
 
 /// Get the views directory for a viewset
@@ -77,11 +394,110 @@ pub fn get_view_path(viewset_name: &str, view_name: &str) -> Result<PathBuf> {
     Ok(get_views_dir(viewset_name)?.join(view_name))
 }
 
+/// Glob [`get_views_dir`] for subdirectories that look like real views (they
+/// contain a `.git`), returning their names in sorted order. Lets callers
+/// reconcile what's actually checked out on disk against `ViewsetsConfig`
+/// (surfacing views present on disk but missing from config, and vice versa)
+/// instead of trusting the YAML alone for which views exist.
+pub fn discover_views(viewset_name: &str) -> Result<Vec<String>> {
+    let views_dir = get_views_dir(viewset_name)?;
+    if !views_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut views: Vec<String> = fs::read_dir(&views_dir)
+        .with_context(|| format!("Failed to read views directory: {}", views_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir() && entry.path().join(".git").exists())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    views.sort();
+    Ok(views)
+}
+
 /// Detect current viewset from working directory
+/// A project-local viewset root discovered by [`find_viewset_marker`]: the
+/// directory the `.viewyard.yaml` marker lives in (so `views/<view>` can be
+/// resolved under it) and the viewset name it declares.
+struct ViewsetMarker {
+    root: PathBuf,
+    viewset_name: String,
+}
+
+/// Shape of the `.viewyard.yaml` marker file that identifies a project-local
+/// viewset root, letting viewset/view detection work outside the hardcoded
+/// `~/src/src-<name>/` layout.
+#[derive(Debug, Deserialize)]
+struct ViewyardMarkerFile {
+    viewset: String,
+}
+
+/// Walk from `start` up toward the filesystem root, git/cargo-style, looking
+/// for a `.viewyard.yaml` marker file. Returns the first ancestor (including
+/// `start` itself) that has one, paired with the viewset name it declares.
+fn find_viewset_marker(start: &Path) -> Option<ViewsetMarker> {
+    let mut dir = Some(start);
+    while let Some(candidate) = dir {
+        let marker_path = candidate.join(".viewyard.yaml");
+        if marker_path.is_file() {
+            if let Ok(content) = fs::read_to_string(&marker_path) {
+                if let Ok(marker) = serde_yaml::from_str::<ViewyardMarkerFile>(&content) {
+                    return Some(ViewsetMarker {
+                        root: candidate.to_path_buf(),
+                        viewset_name: marker.viewset,
+                    });
+                }
+            }
+        }
+        dir = candidate.parent();
+    }
+    None
+}
+
+/// Check `cwd` against every configured viewset's resolved workspace root
+/// (honoring [`Viewset::workspace_root`]/[`ViewsetsConfig::root_template`]),
+/// returning the first `(viewset, view)` pair whose `<root>/views/<view>`
+/// contains `cwd`. Replaces reasoning about a single global template, since
+/// per-viewset overrides mean different viewsets can resolve to unrelated
+/// roots entirely.
+fn detect_viewset_from_configured_roots(cwd: &Path) -> Option<(String, String)> {
+    let config = load_viewsets_config().ok()?;
+
+    for viewset_name in config.viewsets.keys() {
+        let template = root_template_for(viewset_name, Some(&config));
+        let Ok(root) = expand_root_template(&template, viewset_name) else {
+            continue;
+        };
+        let views_dir = root.join("views");
+        if let Ok(relative) = cwd.strip_prefix(&views_dir) {
+            if let Some(view_name) = relative.components().next().and_then(|c| c.as_os_str().to_str()) {
+                return Some((viewset_name.clone(), view_name.to_string()));
+            }
+        }
+    }
+
+    None
+}
+
+/// Detect current viewset from working directory: a `.viewyard.yaml` marker
+/// found by walking up from `current_dir()` takes priority, then matching
+/// `cwd` against every configured viewset's resolved root (see
+/// [`detect_viewset_from_configured_roots`]), falling back to the legacy
+/// `~/src/src-<viewset>/views/<view>` string pattern when neither finds a
+/// match, so layouts that predate templated roots keep working.
 pub fn detect_current_viewset() -> Option<String> {
     let current_dir = std::env::current_dir().ok()?;
+
+    if let Some(marker) = find_viewset_marker(&current_dir) {
+        return Some(marker.viewset_name);
+    }
+
+    if let Some((viewset_name, _)) = detect_viewset_from_configured_roots(&current_dir) {
+        return Some(viewset_name);
+    }
+
     let current_str = current_dir.to_string_lossy();
-    
+
     // Look for pattern like ~/src/src-<viewset>/views/<view>
     if let Some(src_pos) = current_str.find("/src/src-") {
         let after_src = &current_str[src_pos + 9..]; // Skip "/src/src-"
@@ -89,24 +505,45 @@ pub fn detect_current_viewset() -> Option<String> {
             return Some(after_src[..slash_pos].to_string());
         }
     }
-    
+
     None
 }
 
-/// Check if we're currently in a view directory
+/// Check if we're currently in a view directory: same marker-first,
+/// configured-roots-second, pattern-fallback discovery as
+/// [`detect_current_viewset`].
 pub fn detect_current_view() -> Option<(String, String)> {
     let current_dir = std::env::current_dir().ok()?;
+
+    if let Some(marker) = find_viewset_marker(&current_dir) {
+        let relative = current_dir.strip_prefix(&marker.root).ok()?;
+        let parts: Vec<&str> = relative
+            .components()
+            .filter_map(|c| c.as_os_str().to_str())
+            .collect();
+
+        return if parts.len() >= 2 && parts[0] == "views" {
+            Some((marker.viewset_name, parts[1].to_string()))
+        } else {
+            None
+        };
+    }
+
+    if let Some(result) = detect_viewset_from_configured_roots(&current_dir) {
+        return Some(result);
+    }
+
     let current_str = current_dir.to_string_lossy();
-    
+
     // Look for pattern like ~/src/src-<viewset>/views/<view>
     if let Some(src_pos) = current_str.find("/src/src-") {
         let after_src = &current_str[src_pos + 9..]; // Skip "/src/src-"
         let parts: Vec<&str> = after_src.split('/').collect();
-        
+
         if parts.len() >= 3 && parts[1] == "views" {
             return Some((parts[0].to_string(), parts[2].to_string()));
         }
     }
-    
+
     None
 }
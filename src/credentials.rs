@@ -0,0 +1,113 @@
+//! Proactive credential resolution for clone/fetch, tried before falling
+//! back to [`crate::error_handling`]'s manual SSH remediation steps.
+//!
+//! Mirrors jj's `git_utils` credential callback chain: prefer SSH when an
+//! agent looks reachable, otherwise fall back to a GitHub token (`gh auth
+//! token`, then `GITHUB_TOKEN`) and rewrite the clone URL to HTTPS with it
+//! embedded. [`AuthMethod`] lets a viewset pin one transport explicitly for
+//! environments (e.g. behind a corporate firewall) where probing SSH first
+//! just costs a connect timeout before falling through anyway.
+
+use anyhow::{Context, Result};
+
+/// Preferred transport for authenticating to a forge, pinned per-viewset via
+/// [`crate::models::Viewset::preferred_auth_method`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthMethod {
+    /// Prefer SSH when an agent looks reachable, else fall back to HTTPS.
+    #[default]
+    Auto,
+    Ssh,
+    Https,
+}
+
+impl AuthMethod {
+    /// Used as `skip_serializing_if` so a viewset that hasn't pinned a
+    /// transport doesn't grow an explicit `preferred_auth_method: auto` line
+    /// on every save.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::Auto
+    }
+}
+
+/// A resolved credential strategy for one clone/fetch attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Credential {
+    /// Use SSH as-is - any per-account key is already wired up separately via
+    /// [`crate::git::configure_ssh_identity`]'s repo-local `core.sshCommand`.
+    Ssh,
+    /// Clone/fetch from this HTTPS URL (the original rewritten to
+    /// `https://github.com/...`) with this bearer token embedded.
+    HttpsToken { url: String, token: String },
+}
+
+/// Resolve how to authenticate a clone/fetch of `url`, given the viewset's
+/// `preferred` [`AuthMethod`]. `Ssh`/`Https` use that transport outright;
+/// `Auto` prefers SSH when an agent looks reachable and `url` is an SSH
+/// GitHub URL, falling back to an HTTPS-with-token rewrite otherwise.
+pub fn resolve(url: &str, preferred: AuthMethod) -> Result<Credential> {
+    match preferred {
+        AuthMethod::Ssh => Ok(Credential::Ssh),
+        AuthMethod::Https => https_token_credential(url),
+        AuthMethod::Auto => {
+            if is_github_ssh_url(url) && ssh_agent_available() {
+                Ok(Credential::Ssh)
+            } else {
+                https_token_credential(url)
+            }
+        }
+    }
+}
+
+/// Whether an `ssh-agent` is reachable to offer identities - the same
+/// signal OpenSSH itself uses before attempting key auth.
+fn ssh_agent_available() -> bool {
+    std::env::var_os("SSH_AUTH_SOCK").is_some()
+}
+
+fn is_github_ssh_url(url: &str) -> bool {
+    url.starts_with("git@github.com:") || url.starts_with("ssh://git@github.com/")
+}
+
+/// Resolve a GitHub token (`gh auth token`, then `GITHUB_TOKEN`) and rewrite
+/// `url` to its HTTPS equivalent with the token embedded as userinfo.
+fn https_token_credential(url: &str) -> Result<Credential> {
+    let token = crate::github::GitHubService::auth_token()
+        .or_else(|_| std::env::var("GITHUB_TOKEN"))
+        .context("No GitHub token available (gh auth token / GITHUB_TOKEN both unavailable)")?;
+    Ok(Credential::HttpsToken {
+        url: to_https_url(url),
+        token,
+    })
+}
+
+/// Rewrite a `git@github.com:org/repo.git` (or `ssh://` equivalent) URL to
+/// its `https://github.com/org/repo.git` form. URLs that are already HTTPS
+/// (or aren't GitHub) pass through unchanged.
+#[must_use]
+pub fn to_https_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("git@github.com:") {
+        return format!("https://github.com/{rest}");
+    }
+    if let Some(rest) = url.strip_prefix("ssh://git@github.com/") {
+        return format!("https://github.com/{rest}");
+    }
+    url.to_string()
+}
+
+impl Credential {
+    /// The URL to actually clone/fetch from for this credential - `url`
+    /// unchanged for SSH, or the token-embedded HTTPS rewrite.
+    #[must_use]
+    pub fn clone_url(&self, url: &str) -> String {
+        match self {
+            Credential::Ssh => url.to_string(),
+            Credential::HttpsToken { url, token } => {
+                let without_scheme = url.strip_prefix("https://").unwrap_or(url);
+                format!("https://x-access-token:{token}@{without_scheme}")
+            }
+        }
+    }
+}
@@ -1,18 +1,15 @@
+use crate::git::GitError;
 use crate::ui;
 use anyhow::Result;
 
 /// Handle git clone errors with specific recovery guidance
-pub fn handle_clone_error(repo_name: &str, stderr: &str) -> Result<()> {
-    if stderr.contains("Permission denied") || stderr.contains("publickey") {
-        show_ssh_auth_error(repo_name)
-    } else if stderr.contains("not found") || stderr.contains("does not exist") {
-        show_repo_not_found_error(repo_name)
-    } else if stderr.contains("timeout") || stderr.contains("network") {
-        show_network_error(repo_name)
-    } else if stderr.contains("already exists") {
-        show_directory_exists_error(repo_name)
-    } else {
-        show_generic_clone_error(repo_name, stderr)
+pub fn handle_clone_error(repo_name: &str, error: &GitError) -> Result<()> {
+    match error {
+        GitError::SshAuth => show_ssh_auth_error(repo_name),
+        GitError::RepoNotFound => show_repo_not_found_error(repo_name),
+        GitError::Network => show_network_error(repo_name),
+        GitError::DirectoryExists => show_directory_exists_error(repo_name),
+        other => show_generic_clone_error(repo_name, &other.to_string()),
     }
 }
 
@@ -20,12 +17,11 @@ pub fn handle_clone_error(repo_name: &str, stderr: &str) -> Result<()> {
 pub fn handle_checkout_error(
     branch_name: &str,
     repo_path: &std::path::Path,
-    stderr: &str,
+    error: &GitError,
 ) -> Result<()> {
-    if stderr.contains("uncommitted changes") || stderr.contains("would be overwritten") {
-        show_uncommitted_changes_error(branch_name, repo_path)
-    } else {
-        show_generic_checkout_error(branch_name, repo_path, stderr)
+    match error {
+        GitError::UncommittedChanges => show_uncommitted_changes_error(branch_name, repo_path),
+        other => show_generic_checkout_error(branch_name, repo_path, &other.to_string()),
     }
 }
 
@@ -33,12 +29,11 @@ pub fn handle_checkout_error(
 pub fn handle_branch_creation_error(
     branch_name: &str,
     repo_path: &std::path::Path,
-    stderr: &str,
+    error: &GitError,
 ) -> Result<()> {
-    if stderr.contains("already exists") {
-        show_branch_exists_error(branch_name)
-    } else {
-        show_generic_branch_creation_error(branch_name, repo_path, stderr)
+    match error {
+        GitError::BranchExists => show_branch_exists_error(branch_name),
+        other => show_generic_branch_creation_error(branch_name, repo_path, &other.to_string()),
     }
 }
 
@@ -81,13 +76,13 @@ fn show_directory_exists_error(repo_name: &str) -> Result<()> {
     anyhow::bail!("Directory '{repo_name}' already exists")
 }
 
-fn show_generic_clone_error(repo_name: &str, stderr: &str) -> Result<()> {
+fn show_generic_clone_error(repo_name: &str, message: &str) -> Result<()> {
     ui::print_error(&format!("Failed to clone {repo_name}"));
     ui::print_info("Git clone failed:");
-    ui::print_info(&format!("   • Error: {}", stderr.trim()));
+    ui::print_info(&format!("   • Error: {message}"));
     ui::print_info("   • Check repository URL and permissions");
     ui::print_info("   • Verify git and network connectivity");
-    anyhow::bail!("Failed to clone repository '{repo_name}': {stderr}")
+    anyhow::bail!("Failed to clone repository '{repo_name}': {message}")
 }
 
 fn show_uncommitted_changes_error(branch_name: &str, repo_path: &std::path::Path) -> Result<()> {
@@ -105,16 +100,16 @@ fn show_uncommitted_changes_error(branch_name: &str, repo_path: &std::path::Path
 fn show_generic_checkout_error(
     branch_name: &str,
     repo_path: &std::path::Path,
-    stderr: &str,
+    message: &str,
 ) -> Result<()> {
     ui::print_error(&format!("Failed to checkout branch '{branch_name}'"));
     ui::print_info("Branch checkout failed:");
-    ui::print_info(&format!("   • Error: {}", stderr.trim()));
+    ui::print_info(&format!("   • Error: {message}"));
     ui::print_info(&format!(
         "   • Check branch status: cd {} && git status",
         repo_path.display()
     ));
-    anyhow::bail!("Failed to checkout branch '{branch_name}': {stderr}")
+    anyhow::bail!("Failed to checkout branch '{branch_name}': {message}")
 }
 
 fn show_branch_exists_error(branch_name: &str) -> Result<()> {
@@ -133,14 +128,14 @@ fn show_branch_exists_error(branch_name: &str) -> Result<()> {
 fn show_generic_branch_creation_error(
     branch_name: &str,
     repo_path: &std::path::Path,
-    stderr: &str,
+    message: &str,
 ) -> Result<()> {
     ui::print_error(&format!("Failed to create branch '{branch_name}'"));
     ui::print_info("Branch creation failed:");
-    ui::print_info(&format!("   • Error: {}", stderr.trim()));
+    ui::print_info(&format!("   • Error: {message}"));
     ui::print_info(&format!(
         "   • Check repository state: cd {} && git status",
         repo_path.display()
     ));
-    anyhow::bail!("Failed to create branch '{branch_name}': {stderr}")
+    anyhow::bail!("Failed to create branch '{branch_name}': {message}")
 }
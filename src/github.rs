@@ -1,15 +1,419 @@
 use anyhow::{Context, Result};
-use std::process::Command;
 
+use crate::git::create_command;
 use crate::models::Repository;
 
+/// Which transport `GitHubService` uses to talk to GitHub.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// Shell out to the `gh` CLI (requires install + `gh auth login`).
+    #[default]
+    Cli,
+    /// Talk to `https://api.github.com` directly using a `GITHUB_TOKEN`.
+    Api,
+}
+
+impl Backend {
+    /// Pick a backend based on environment: use the REST API when
+    /// `GITHUB_TOKEN` is set (handy for CI/headless servers), otherwise fall
+    /// back to the `gh` CLI.
+    #[must_use]
+    pub fn detect() -> Self {
+        if std::env::var("GITHUB_TOKEN").is_ok() {
+            Self::Api
+        } else {
+            Self::Cli
+        }
+    }
+}
+
+/// Which categories of repository to include in a discovery pass. Beyond
+/// repos you own or belong to an org you're in, people commonly also want
+/// starred repos and ones where they're a collaborator but not the owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepoScope {
+    Owned,
+    Organizations,
+    Starred,
+    Collaborator,
+}
+
+impl RepoScope {
+    /// The default scope set used when a caller doesn't pick explicitly:
+    /// owned + organization repos, matching today's behavior.
+    #[must_use]
+    pub fn defaults() -> std::collections::HashSet<Self> {
+        [Self::Owned, Self::Organizations].into_iter().collect()
+    }
+
+    /// Every scope, for callers that want the broadest possible discovery.
+    #[must_use]
+    pub fn all() -> std::collections::HashSet<Self> {
+        [Self::Owned, Self::Organizations, Self::Starred, Self::Collaborator]
+            .into_iter()
+            .collect()
+    }
+}
+
+const GITHUB_API_BASE: &str = "https://api.github.com";
+
+fn github_token() -> Result<String> {
+    std::env::var("GITHUB_TOKEN")
+        .context("GITHUB_TOKEN environment variable not set (required for the API backend)")
+}
+
+/// GitHub rate-limit bucket a request falls into (GitHub tracks these
+/// separately; `core` covers most REST endpoints we use here).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitCategory {
+    Core,
+    Search,
+}
+
+/// Snapshot of a rate-limit bucket's quota, as reported by GitHub's
+/// `X-RateLimit-*` response headers.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitInfo {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_epoch: u64,
+}
+
+/// Below this many remaining requests we start inserting proportional
+/// backoff rather than firing at full speed, so a long multi-account sweep
+/// doesn't burn through the rest of the quota in a burst.
+const LOW_QUOTA_THRESHOLD: u32 = 10;
+
+/// Tracks remaining GitHub API quota per category so the discovery path can
+/// throttle itself *before* a request fails, instead of only reacting after
+/// a 403 rate-limit response comes back.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    core: std::sync::Mutex<Option<RateLimitInfo>>,
+    search: std::sync::Mutex<Option<RateLimitInfo>>,
+}
+
+impl RateLimiter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn slot(&self, category: RateLimitCategory) -> &std::sync::Mutex<Option<RateLimitInfo>> {
+        match category {
+            RateLimitCategory::Core => &self.core,
+            RateLimitCategory::Search => &self.search,
+        }
+    }
+
+    /// Record the quota reported by a response's rate-limit headers.
+    pub fn record(&self, category: RateLimitCategory, response: &ureq::Response) {
+        let Some(info) = parse_rate_limit_headers(response) else {
+            return;
+        };
+        *self.slot(category).lock().unwrap() = Some(info);
+    }
+
+    /// Block (or, in non-blocking mode, return an error) until there's
+    /// quota available for `category`. Called before issuing a request.
+    pub fn throttle_before_request(&self, category: RateLimitCategory, blocking: bool) -> Result<()> {
+        let Some(info) = *self.slot(category).lock().unwrap() else {
+            return Ok(());
+        };
+
+        if info.remaining == 0 {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let wait_secs = info.reset_epoch.saturating_sub(now);
+
+            if !blocking {
+                anyhow::bail!("Rate limit exhausted; throttled until epoch {}", info.reset_epoch);
+            }
+            if wait_secs > 0 {
+                use crate::ui;
+                ui::print_warning(&format!(
+                    "GitHub rate limit exhausted, waiting {wait_secs}s for reset"
+                ));
+                std::thread::sleep(std::time::Duration::from_secs(wait_secs));
+            }
+        } else if info.remaining < LOW_QUOTA_THRESHOLD {
+            // Proportional backoff as we approach exhaustion: the closer to
+            // zero, the longer we pause between requests.
+            let backoff_ms = 200 * (LOW_QUOTA_THRESHOLD - info.remaining) as u64;
+            std::thread::sleep(std::time::Duration::from_millis(backoff_ms));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_rate_limit_headers(response: &ureq::Response) -> Option<RateLimitInfo> {
+    let limit = response.header("X-RateLimit-Limit")?.parse().ok()?;
+    let remaining = response.header("X-RateLimit-Remaining")?.parse().ok()?;
+    let reset_epoch = response.header("X-RateLimit-Reset")?.parse().ok()?;
+    Some(RateLimitInfo {
+        limit,
+        remaining,
+        reset_epoch,
+    })
+}
+
+/// Lazily-initialized rate limiter shared across every GitHub API/GraphQL call.
+fn shared_rate_limiter() -> &'static RateLimiter {
+    static LIMITER: std::sync::OnceLock<RateLimiter> = std::sync::OnceLock::new();
+    LIMITER.get_or_init(RateLimiter::new)
+}
+
+/// A GraphQL response envelope: `data` may be present alongside partial
+/// `errors` (e.g. an org whose repos the token can't read), so callers can
+/// keep whatever came back instead of failing the whole query.
+#[derive(Debug, serde::Deserialize)]
+struct GraphResult<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphError>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GraphError {
+    message: String,
+}
+
+/// Execute a single GraphQL request against `/graphql`, returning the parsed
+/// envelope so the caller can inspect `data` and `errors` independently.
+fn graphql_request<T: serde::de::DeserializeOwned>(query: &str, variables: serde_json::Value) -> Result<GraphResult<T>> {
+    let token = github_token()?;
+    let limiter = shared_rate_limiter();
+    limiter.throttle_before_request(RateLimitCategory::Core, true)?;
+
+    let body = serde_json::json!({ "query": query, "variables": variables });
+    let response = ureq::post(&format!("{GITHUB_API_BASE}/graphql"))
+        .set("Authorization", &format!("token {token}"))
+        .set("User-Agent", "viewyard")
+        .send_json(body)
+        .context("GitHub GraphQL request failed")?;
+
+    limiter.record(RateLimitCategory::Core, &response);
+
+    response
+        .into_json()
+        .context("Failed to parse GitHub GraphQL response")
+}
+
+/// Query fetching the viewer's own repositories, cursor-paginated via
+/// `first`/`after`, plus the first 100 organizations and the first 100 repos
+/// of each. The caller never advances `$orgAfter`, and organization repos
+/// have no cursor, so anything past those first pages is not fetched.
+const DISCOVER_REPOS_QUERY: &str = r"
+query($after: String, $orgAfter: String) {
+  viewer {
+    login
+    repositories(first: 100, after: $after, ownerAffiliations: OWNER) {
+      nodes { name sshUrl isPrivate }
+      pageInfo { hasNextPage endCursor }
+    }
+    organizations(first: 100, after: $orgAfter) {
+      nodes {
+        login
+        repositories(first: 100) {
+          nodes { name sshUrl isPrivate }
+          pageInfo { hasNextPage endCursor }
+        }
+      }
+      pageInfo { hasNextPage endCursor }
+    }
+  }
+}
+";
+
+/// Discover an account's owned and organization repositories in one
+/// cursor-paginated GraphQL query instead of one REST call per organization.
+/// Partial errors (e.g. an org the token can't read) are surfaced as
+/// warnings; repositories that did come back are still returned.
+fn discover_repositories_via_graphql(account: &str) -> Result<Vec<Repository>> {
+    let mut repos = Vec::new();
+    let mut after: Option<String> = None;
+
+    loop {
+        let result: GraphResult<serde_json::Value> = graphql_request(
+            DISCOVER_REPOS_QUERY,
+            serde_json::json!({ "after": after, "orgAfter": serde_json::Value::Null }),
+        )?;
+
+        for error in &result.errors {
+            eprintln!("Warning: GitHub GraphQL error: {}", error.message);
+        }
+
+        let Some(data) = result.data else {
+            break;
+        };
+
+        let viewer = &data["viewer"];
+        // Organization nodes come back identical on every page, so they are
+        // only read from the first one.
+        let first_page = after.is_none();
+
+        for node in viewer["repositories"]["nodes"].as_array().into_iter().flatten() {
+            if let Some(repo) = repo_from_graphql_node(node, account, None) {
+                repos.push(repo);
+            }
+        }
+
+        for org_node in viewer["organizations"]["nodes"].as_array().into_iter().flatten().filter(|_| first_page) {
+            let org_login = org_node["login"].as_str().unwrap_or_default();
+            for node in org_node["repositories"]["nodes"].as_array().into_iter().flatten() {
+                if let Some(repo) = repo_from_graphql_node(node, account, Some(org_login)) {
+                    repos.push(repo);
+                }
+            }
+            if org_node["repositories"]["pageInfo"]["hasNextPage"].as_bool() == Some(true) {
+                eprintln!(
+                    "Warning: organization '{org_login}' has more than 100 repositories; only the first page was fetched"
+                );
+            }
+        }
+
+        let page_info = &viewer["repositories"]["pageInfo"];
+        if page_info["hasNextPage"].as_bool() == Some(true) {
+            after = page_info["endCursor"].as_str().map(str::to_string);
+        } else {
+            break;
+        }
+
+        if viewer["organizations"]["pageInfo"]["hasNextPage"].as_bool() == Some(true) {
+            eprintln!("Warning: account belongs to more than 100 organizations; only the first page was fetched");
+        }
+    }
+
+    Ok(repos)
+}
+
+/// Extract `language`/`topics` from a raw GitHub REST API repo object
+/// (`.language`, `.topics`), as returned by `/orgs/{org}/repos` and similar
+/// endpoints.
+fn rest_language_and_topics(repo_data: &serde_json::Value) -> (Option<String>, Vec<String>) {
+    let language = repo_data["language"].as_str().map(str::to_string);
+    let topics = repo_data["topics"]
+        .as_array()
+        .map(|topics| topics.iter().filter_map(|t| t.as_str().map(str::to_string)).collect())
+        .unwrap_or_default();
+    (language, topics)
+}
+
+/// Extract `language`/`topics` from a `gh repo list --json
+/// primaryLanguage,repositoryTopics` entry, which nests both under their own
+/// objects rather than returning raw scalars/arrays like the REST API does.
+fn cli_language_and_topics(repo_data: &serde_json::Value) -> (Option<String>, Vec<String>) {
+    let language = repo_data["primaryLanguage"]["name"].as_str().map(str::to_string);
+    let topics = repo_data["repositoryTopics"]
+        .as_array()
+        .map(|topics| {
+            topics
+                .iter()
+                .filter_map(|t| t["name"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    (language, topics)
+}
+
+fn repo_from_graphql_node(node: &serde_json::Value, account: &str, org: Option<&str>) -> Option<Repository> {
+    let name = node["name"].as_str()?;
+    let url = node["sshUrl"].as_str()?;
+    let is_private = node["isPrivate"].as_bool().unwrap_or(false);
+    let privacy_indicator = if is_private { " [private]" } else { "" };
+
+    let source = match org {
+        Some(org) => format!("GitHub ({org}/{account}){privacy_indicator}"),
+        None => format!("GitHub ({account}){privacy_indicator}"),
+    };
+
+    Some(Repository {
+        name: name.to_string(),
+        url: url.to_string(),
+        is_private,
+        source,
+        account: Some(account.to_string()),
+        category: None,
+        backend: None,
+        // The GraphQL query backing this path doesn't currently request
+        // topics/primaryLanguage; see get_user_repositories for the path
+        // that does.
+        topics: Vec::new(),
+        language: None,
+        clone_strategy: None,
+    })
+}
+
+/// Discover every repository belonging to a GitHub org by name via
+/// `GET /orgs/{org}/repos`, independent of which account (if any) is
+/// authenticated. Unlike [`GitHubService::discover_repositories_from_account`],
+/// this targets the org directly rather than the orgs an authenticated
+/// identity belongs to, so it also works for orgs the token isn't a member
+/// of but can merely read. Used by `viewyard viewset sync github:<org>`.
+pub fn discover_org_repositories(org: &str) -> Result<Vec<Repository>> {
+    let token = github_token()?;
+    let limiter = shared_rate_limiter();
+    let mut repos = Vec::new();
+    let mut page: u32 = 1;
+
+    loop {
+        limiter.throttle_before_request(RateLimitCategory::Core, true)?;
+        let response = ureq::get(&format!("{GITHUB_API_BASE}/orgs/{org}/repos"))
+            .query("per_page", "100")
+            .query("page", &page.to_string())
+            .set("Authorization", &format!("token {token}"))
+            .set("User-Agent", "viewyard")
+            .call()
+            .with_context(|| format!("Failed to list repositories for GitHub org '{org}'"))?;
+
+        limiter.record(RateLimitCategory::Core, &response);
+
+        let page_repos: Vec<serde_json::Value> = response
+            .into_json()
+            .context("Failed to parse GitHub org repositories response")?;
+        let fetched = page_repos.len();
+
+        for repo_data in &page_repos {
+            if let (Some(name), Some(url), Some(is_private)) = (
+                repo_data["name"].as_str(),
+                repo_data["ssh_url"].as_str(),
+                repo_data["private"].as_bool(),
+            ) {
+                let (language, topics) = rest_language_and_topics(repo_data);
+                repos.push(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("GitHub ({org})"),
+                    account: None,
+                    category: None,
+                    backend: None,
+                    topics,
+                    language,
+                    clone_strategy: None,
+                });
+            }
+        }
+
+        if fetched < 100 {
+            break;
+        }
+        page += 1;
+    }
+
+    Ok(repos)
+}
+
 #[derive(Debug)]
 pub struct GitHubService;
 
 impl GitHubService {
     /// Check if GitHub CLI is available and authenticated
     pub fn check_availability() -> Result<bool> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args(["--version"])
             .output()
             .context("Failed to check if gh CLI is installed")?;
@@ -19,7 +423,7 @@ impl GitHubService {
         }
 
         // Check if authenticated
-        let auth_output = Command::new("gh")
+        let auth_output = create_command("gh")
             .args(["auth", "status"])
             .output()
             .context("Failed to check gh CLI authentication status")?;
@@ -29,7 +433,7 @@ impl GitHubService {
 
     /// Get list of available GitHub accounts
     pub fn get_available_accounts() -> Result<Vec<String>> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args(["auth", "status"])
             .output()
             .context("Failed to get GitHub auth status")?;
@@ -57,9 +461,34 @@ impl GitHubService {
         Ok(accounts)
     }
 
+    /// Resolve a GitHub token the same way `gh` itself would for an
+    /// authenticated request (`gh auth token`), for callers (e.g.
+    /// [`crate::credentials`]) that need one outside of `gh`'s own
+    /// subcommands - notably to embed in an HTTPS clone URL when SSH isn't
+    /// available.
+    pub fn auth_token() -> Result<String> {
+        let output = create_command("gh")
+            .args(["auth", "token"])
+            .output()
+            .context("Failed to execute 'gh auth token'")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "'gh auth token' failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if token.is_empty() {
+            anyhow::bail!("'gh auth token' returned an empty token");
+        }
+        Ok(token)
+    }
+
     /// Get current authenticated account
     pub fn get_current_account() -> Result<String> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args(["api", "user", "--jq", ".login"])
             .output()
             .context("Failed to get current GitHub account")?;
@@ -74,7 +503,7 @@ impl GitHubService {
 
     /// Switch to a specific GitHub account
     pub fn switch_account(account: &str) -> Result<()> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args(["auth", "switch", "--user", account])
             .output()
             .context("Failed to switch GitHub account")?;
@@ -87,8 +516,31 @@ impl GitHubService {
         Ok(())
     }
 
-    /// Discover repositories from a specific GitHub account
+    /// Discover repositories from a specific GitHub account, using the `gh`
+    /// CLI or the REST API depending on [`Backend::detect`].
     pub fn discover_repositories_from_account(account: &str) -> Result<Vec<Repository>> {
+        Self::discover_repositories_from_account_scoped(account, &RepoScope::defaults())
+    }
+
+    /// Like [`Self::discover_repositories_from_account`], but only fetching
+    /// the requested [`RepoScope`]s (owned/org/starred/collaborator). Only the
+    /// `gh` CLI backend honors `scopes`; the API backend fetches owned and
+    /// organization repositories whatever `scopes` holds, and never starred
+    /// or collaborator ones.
+    pub fn discover_repositories_from_account_scoped(
+        account: &str,
+        scopes: &std::collections::HashSet<RepoScope>,
+    ) -> Result<Vec<Repository>> {
+        match Backend::detect() {
+            Backend::Cli => Self::discover_repositories_from_account_cli(account, scopes),
+            Backend::Api => Self::discover_repositories_from_account_api(account),
+        }
+    }
+
+    fn discover_repositories_from_account_cli(
+        account: &str,
+        scopes: &std::collections::HashSet<RepoScope>,
+    ) -> Result<Vec<Repository>> {
         use crate::ui;
 
         let mut repos = Vec::new();
@@ -97,25 +549,131 @@ impl GitHubService {
         ui::print_info(&format!("  Switching to account: {account}"));
         Self::switch_account(account)?;
 
-        // Get user repositories
-        ui::print_info(&format!("  Fetching personal repositories for {account}"));
-        let user_repos = Self::get_user_repositories(account)?;
-        ui::print_info(&format!(
-            "    Found {} personal repositories",
-            user_repos.len()
-        ));
-        repos.extend(user_repos);
+        if scopes.contains(&RepoScope::Owned) {
+            ui::print_info(&format!("  Fetching personal repositories for {account}"));
+            let user_repos = Self::get_user_repositories(account)?;
+            ui::print_info(&format!(
+                "    Found {} personal repositories",
+                user_repos.len()
+            ));
+            repos.extend(user_repos);
+        }
+
+        if scopes.contains(&RepoScope::Organizations) {
+            ui::print_info(&format!(
+                "  Fetching organization repositories for {account}"
+            ));
+            let org_repos = Self::get_organization_repositories(account)?;
+            ui::print_info(&format!(
+                "    Found {} organization repositories",
+                org_repos.len()
+            ));
+            repos.extend(org_repos);
+        }
+
+        if scopes.contains(&RepoScope::Starred) {
+            ui::print_info(&format!("  Fetching starred repositories for {account}"));
+            let starred_repos = Self::get_starred_repositories(account)?;
+            ui::print_info(&format!("    Found {} starred repositories", starred_repos.len()));
+            repos.extend(starred_repos);
+        }
+
+        if scopes.contains(&RepoScope::Collaborator) {
+            ui::print_info(&format!(
+                "  Fetching collaborator repositories for {account}"
+            ));
+            let collab_repos = Self::get_collaborator_repositories(account)?;
+            ui::print_info(&format!(
+                "    Found {} collaborator repositories",
+                collab_repos.len()
+            ));
+            repos.extend(collab_repos);
+        }
 
-        // Get organization repositories
         ui::print_info(&format!(
-            "  Fetching organization repositories for {account}"
+            "  Total repositories for {}: {}",
+            account,
+            repos.len()
         ));
-        let org_repos = Self::get_organization_repositories(account)?;
+        Ok(repos)
+    }
+
+    /// Get repositories the account has starred
+    fn get_starred_repositories(account: &str) -> Result<Vec<Repository>> {
+        let output = create_command("gh")
+            .args(["api", "user/starred", "--paginate", "--jq", ".[] | {name, sshUrl: .ssh_url, isPrivate: .private, language, topics}"])
+            .output()
+            .context("Failed to get starred repositories")?;
+
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        Self::parse_jsonl_repos(&output.stdout, account, "starred")
+    }
+
+    /// Get repositories where the account is a collaborator but not the owner
+    fn get_collaborator_repositories(account: &str) -> Result<Vec<Repository>> {
+        let output = create_command("gh")
+            .args([
+                "api",
+                "user/repos?affiliation=collaborator",
+                "--paginate",
+                "--jq",
+                ".[] | {name, sshUrl: .ssh_url, isPrivate: .private, language, topics}",
+            ])
+            .output()
+            .context("Failed to get collaborator repositories")?;
+
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        Self::parse_jsonl_repos(&output.stdout, account, "collaborator")
+    }
+
+    /// Parse newline-delimited JSON objects (as produced by `gh api --jq`)
+    /// into `Repository` values tagged with the given scope label.
+    fn parse_jsonl_repos(stdout: &[u8], account: &str, scope_label: &str) -> Result<Vec<Repository>> {
+        let stdout = String::from_utf8_lossy(stdout);
+        let mut repos = Vec::new();
+
+        for line in stdout.lines().filter(|l| !l.trim().is_empty()) {
+            let value: serde_json::Value =
+                serde_json::from_str(line).context("Failed to parse repository JSON line")?;
+            if let (Some(name), Some(url), Some(is_private)) = (
+                value["name"].as_str(),
+                value["sshUrl"].as_str(),
+                value["isPrivate"].as_bool(),
+            ) {
+                let (language, topics) = rest_language_and_topics(&value);
+                repos.push(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("GitHub ({account}) [{scope_label}]"),
+                    account: Some(account.to_string()),
+                    category: None,
+                    backend: None,
+                    topics,
+                    language,
+                    clone_strategy: None,
+                });
+            }
+        }
+
+        Ok(repos)
+    }
+
+    /// Discover repositories over the GraphQL API using `GITHUB_TOKEN`, with no
+    /// dependency on the `gh` CLI or an interactive login.
+    fn discover_repositories_from_account_api(account: &str) -> Result<Vec<Repository>> {
+        use crate::ui;
+
         ui::print_info(&format!(
-            "    Found {} organization repositories",
-            org_repos.len()
+            "  Fetching owned and organization repositories for {account} (GraphQL)"
         ));
-        repos.extend(org_repos);
+        let repos = discover_repositories_via_graphql(account)?;
 
         ui::print_info(&format!(
             "  Total repositories for {}: {}",
@@ -127,14 +685,14 @@ impl GitHubService {
 
     /// Get user's personal repositories
     fn get_user_repositories(account: &str) -> Result<Vec<Repository>> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args([
                 "repo",
                 "list",
                 "--limit",
                 "1000",
                 "--json",
-                "name,sshUrl,isPrivate",
+                "name,sshUrl,isPrivate,primaryLanguage,repositoryTopics",
             ])
             .output()
             .context("Failed to get user repositories")?;
@@ -186,12 +744,18 @@ impl GitHubService {
                 repo_data["isPrivate"].as_bool(),
             ) {
                 let privacy_indicator = if is_private { " [private]" } else { "" };
+                let (language, topics) = cli_language_and_topics(&repo_data);
                 repos.push(Repository {
                     name: name.to_string(),
                     url: url.to_string(),
                     is_private,
                     source: format!("GitHub ({account}){privacy_indicator}"),
                     account: Some(account.to_string()),
+                    category: None,
+                    backend: None,
+                    topics,
+                    language,
+                    clone_strategy: None,
                 });
             }
         }
@@ -208,7 +772,7 @@ impl GitHubService {
     /// Get repositories from organizations the user belongs to
     fn get_organization_repositories(account: &str) -> Result<Vec<Repository>> {
         // First, get list of organizations
-        let orgs_output = Command::new("gh")
+        let orgs_output = create_command("gh")
             .args(["api", "user/orgs", "--jq", ".[].login"])
             .output()
             .context("Failed to get user organizations")?;
@@ -252,7 +816,7 @@ impl GitHubService {
 
     /// Get repositories for a specific organization
     fn get_repositories_for_organization(org: &str, account: &str) -> Result<Vec<Repository>> {
-        let output = Command::new("gh")
+        let output = create_command("gh")
             .args([
                 "repo",
                 "list",
@@ -260,7 +824,7 @@ impl GitHubService {
                 "--limit",
                 "1000",
                 "--json",
-                "name,sshUrl,isPrivate",
+                "name,sshUrl,isPrivate,primaryLanguage,repositoryTopics",
             ])
             .output()
             .context("Failed to get organization repositories")?;
@@ -284,12 +848,18 @@ impl GitHubService {
                 repo_data["isPrivate"].as_bool(),
             ) {
                 let privacy_indicator = if is_private { " [private]" } else { "" };
+                let (language, topics) = cli_language_and_topics(&repo_data);
                 repos.push(Repository {
                     name: name.to_string(),
                     url: url.to_string(),
                     is_private,
                     source: format!("GitHub ({org}/{account}){privacy_indicator}"),
                     account: Some(account.to_string()),
+                    category: None,
+                    backend: None,
+                    topics,
+                    language,
+                    clone_strategy: None,
                 });
             }
         }
@@ -339,3 +909,35 @@ impl GitHubService {
         Ok(all_repos)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter_non_blocking_throttled_error() {
+        let limiter = RateLimiter::new();
+        *limiter.core.lock().unwrap() = Some(RateLimitInfo {
+            limit: 5000,
+            remaining: 0,
+            reset_epoch: u64::MAX,
+        });
+
+        let result = limiter.throttle_before_request(RateLimitCategory::Core, false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_passes_through_with_quota() {
+        let limiter = RateLimiter::new();
+        *limiter.core.lock().unwrap() = Some(RateLimitInfo {
+            limit: 5000,
+            remaining: 4999,
+            reset_epoch: 0,
+        });
+
+        assert!(limiter
+            .throttle_before_request(RateLimitCategory::Core, true)
+            .is_ok());
+    }
+}
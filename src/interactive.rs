@@ -1,27 +1,96 @@
 use crate::models::Repository;
-use crate::search::RepositorySearch;
+use crate::search::{filter_repositories, RepositorySearch};
 use anyhow::Result;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::io::{self, Write};
+use std::path::PathBuf;
 
+#[derive(Default)]
 pub struct InteractiveSelector {
-    search: RepositorySearch,
+    /// Whether to look up and display each repo's local branch/dirty status.
+    /// Off by default so pure-remote listings (nothing cloned yet) skip the
+    /// filesystem work entirely.
+    show_vcs_status: bool,
+    /// Per-path cache of `(branch_or_sha, rendered_status_symbols)` so
+    /// repeatedly narrowing the search doesn't re-run git for every repo on
+    /// every keystroke.
+    vcs_status_cache: RefCell<HashMap<PathBuf, (String, String)>>,
 }
 
 impl InteractiveSelector {
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            search: RepositorySearch::new(),
+        Self::default()
+    }
+
+    /// Enable the local branch/dirty status column in selection listings.
+    #[must_use]
+    pub fn with_vcs_status(mut self, enabled: bool) -> Self {
+        self.show_vcs_status = enabled;
+        self
+    }
+
+    /// Render ` [branch ⇡2!]`-style starship-inspired status for a repo
+    /// already cloned at `<cwd>/<repo.name>`, or an empty string if it isn't
+    /// cloned or VCS status display is disabled. Ahead/behind counts,
+    /// diverged state, and dirty-tree flags come from
+    /// [`crate::git::status_summary`]; results are cached per path so
+    /// repeatedly narrowing a search doesn't re-run git for every repo on
+    /// every keystroke.
+    fn vcs_status_suffix(&self, repo: &Repository) -> String {
+        if !self.show_vcs_status {
+            return String::new();
+        }
+
+        let Ok(cwd) = std::env::current_dir() else {
+            return String::new();
+        };
+        let repo_path = cwd.join(&repo.name);
+
+        if let Some((label, symbols)) = self.vcs_status_cache.borrow().get(&repo_path) {
+            return format!(" [{label} {symbols}]");
+        }
+
+        if !crate::git::is_git_repo(&repo_path) {
+            return String::new();
         }
+
+        let branch = crate::git::get_current_branch(&repo_path).unwrap_or_default();
+        let label = if branch.is_empty() {
+            crate::git::get_short_head_sha(&repo_path).unwrap_or_else(|_| "unknown".to_string())
+        } else {
+            branch
+        };
+        let symbols = crate::git::status_summary(&repo_path)
+            .map(|s| s.render())
+            .unwrap_or_else(|_| "?".to_string());
+
+        self.vcs_status_cache
+            .borrow_mut()
+            .insert(repo_path, (label.clone(), symbols.clone()));
+
+        format!(" [{label} {symbols}]")
     }
 
-    /// Interactive repository selection with iterative search and numbered list selection
+    /// Interactive repository selection. Prefers the full-screen fuzzy picker
+    /// ([`crate::picker::pick_repositories_flat`]) when stdin/stdout are a
+    /// real terminal, falling back to the line-based search-then-numbered-list
+    /// loop below for piped/non-TTY invocations, where raw mode and cursor
+    /// control don't make sense.
     pub fn select_repositories(&self, repositories: &[Repository]) -> Result<Vec<Repository>> {
         if repositories.is_empty() {
             println!("No repositories found.");
             return Ok(Vec::new());
         }
 
+        if crate::picker::is_interactive_terminal() {
+            return Ok(crate::picker::pick_repositories_flat(repositories, "")?
+                .into_iter()
+                .cloned()
+                .collect());
+        }
+
         println!("🔍 Repository Selection");
         println!("Found {} repositories", repositories.len());
         println!();
@@ -43,7 +112,7 @@ impl InteractiveSelector {
             }
 
             // Get search query
-            print!("Search repositories (or 'done' to finish): ");
+            print!("Search repositories ('category:<name>', 'all', or 'done' to finish): ");
             io::stdout().flush()?;
 
             let mut input = String::new();
@@ -54,12 +123,21 @@ impl InteractiveSelector {
                 break;
             }
 
-            // Find matching repositories
+            // Find matching repositories, narrowing incrementally on each
+            // keystroke-equivalent (name or source fuzzy match)
             let repos_to_show = if query == "all" {
                 repositories.to_vec()
+            } else if let Some(category) = query.strip_prefix("category:") {
+                repositories
+                    .iter()
+                    .filter(|repo| repo.category.as_deref() == Some(category))
+                    .cloned()
+                    .collect()
             } else {
-                let matches = self.search.search(repositories, query);
-                matches.into_iter().map(|(repo, _score)| repo).collect()
+                filter_repositories(repositories, query)
+                    .into_iter()
+                    .map(|(repo, _score)| repo)
+                    .collect()
             };
 
             if repos_to_show.is_empty() {
@@ -86,10 +164,11 @@ impl InteractiveSelector {
             println!("Found {} repositories:", available_repos.len());
             for (i, repo) in available_repos.iter().enumerate() {
                 println!(
-                    "  {}. {} ({})",
+                    "  {}. {} ({}){}",
                     i + 1,
                     repo.name,
-                    Self::format_source(&repo.source)
+                    Self::format_source(&repo.source),
+                    self.vcs_status_suffix(repo)
                 );
             }
             println!();
@@ -129,7 +208,20 @@ impl InteractiveSelector {
         Ok(selected_repos)
     }
 
-    /// Show overview of available repositories grouped by source
+    /// Like [`Self::select_repositories`], but for callers (`viewset
+    /// update`) that already show `existing_repos` for context before
+    /// prompting - `available_repos` has already had those names filtered
+    /// out, so this only needs to run the same picker/search flow.
+    pub fn select_repositories_with_existing(
+        &self,
+        available_repos: &[Repository],
+        _existing_repos: &[Repository],
+    ) -> Result<Vec<Repository>> {
+        self.select_repositories(available_repos)
+    }
+
+    /// Show overview of available repositories grouped by source and, if any
+    /// carry one, by category
     fn show_repository_overview(repositories: &[Repository]) {
         let groups = RepositorySearch::group_by_source(repositories);
 
@@ -137,6 +229,14 @@ impl InteractiveSelector {
         for (source, repos) in &groups {
             println!("  📂 {}: {} repositories", source, repos.len());
         }
+
+        let category_groups = RepositorySearch::group_by_category(repositories);
+        if !category_groups.is_empty() {
+            println!("Available repositories by category:");
+            for (category, repos) in &category_groups {
+                println!("  🏷️  {}: {} repositories", category, repos.len());
+            }
+        }
     }
 
     /// Format repository source for display
@@ -153,20 +253,75 @@ impl InteractiveSelector {
         source.to_string()
     }
 
-    /// Parse user selection input into repository indices
+    /// Resolve a single token (`"3"`, `"3-"`, `"-5"`, `"5-1"`, or `"all"`) into
+    /// the 1-based indices it refers to, in the order they should be visited -
+    /// descending for a reverse range like `5-1`. An open-ended bound (`"3-"`
+    /// or `"-5"`) stands in for `max_index` or `1` respectively.
+    fn resolve_indices(token: &str, max_index: usize) -> Result<Vec<usize>, String> {
+        if token == "all" {
+            return Ok((1..=max_index).collect());
+        }
+
+        if let Some(dash_pos) = token.find('-') {
+            let (start_str, end_str) = (&token[..dash_pos], &token[dash_pos + 1..]);
+            let start: usize = if start_str.is_empty() {
+                1
+            } else {
+                start_str
+                    .parse()
+                    .map_err(|_| format!("Invalid number: '{start_str}'"))?
+            };
+            let end: usize = if end_str.is_empty() {
+                max_index
+            } else {
+                end_str
+                    .parse()
+                    .map_err(|_| format!("Invalid number: '{end_str}'"))?
+            };
+
+            if start == 0 || end == 0 {
+                return Err("Numbers must start from 1".to_string());
+            }
+            if start > max_index || end > max_index {
+                return Err(format!("Numbers must be between 1 and {max_index}"));
+            }
+
+            if start > end {
+                return Ok((end..=start).rev().collect());
+            }
+            return Ok((start..=end).collect());
+        }
+
+        let index: usize = token
+            .parse()
+            .map_err(|_| format!("Invalid number: '{token}'"))?;
+
+        if index == 0 {
+            return Err("Numbers must start from 1".to_string());
+        }
+        if index > max_index {
+            return Err(format!("Number must be between 1 and {max_index}"));
+        }
+
+        Ok(vec![index])
+    }
+
+    /// Parse user selection input into repository indices.
+    ///
+    /// Tokens are applied left to right into an ordered, dedup-by-name
+    /// accumulator: plain numbers and `all` add repos, ranges may be
+    /// open-ended (`3-`, `-5`) or descending (`5-1`, expanding in that
+    /// order), and a token prefixed with `!` or `^` (e.g. `!5-7`) removes
+    /// the referenced repos from whatever has been accumulated so far.
     fn parse_selection(
         input: &str,
         available_repos: &[Repository],
     ) -> Result<Vec<Repository>, String> {
         let input = input.trim();
-
-        if input == "all" {
-            return Ok(available_repos.to_vec());
-        }
-
-        let mut selected = Vec::new();
         let max_index = available_repos.len();
 
+        let mut selected: Vec<Repository> = Vec::new();
+
         // Split by comma or space
         let parts: Vec<&str> = input
             .split(&[',', ' '][..])
@@ -175,49 +330,17 @@ impl InteractiveSelector {
             .collect();
 
         for part in parts {
-            if part.contains('-') {
-                // Handle range (e.g., "1-5")
-                let range_parts: Vec<&str> = part.split('-').collect();
-                if range_parts.len() != 2 {
-                    return Err(format!("Invalid range format: '{part}'"));
-                }
-
-                let start: usize = range_parts[0]
-                    .parse()
-                    .map_err(|_| format!("Invalid number: '{}'", range_parts[0]))?;
-                let end: usize = range_parts[1]
-                    .parse()
-                    .map_err(|_| format!("Invalid number: '{}'", range_parts[1]))?;
-
-                if start == 0 || end == 0 {
-                    return Err("Numbers must start from 1".to_string());
-                }
-                if start > max_index || end > max_index {
-                    return Err(format!("Numbers must be between 1 and {max_index}"));
-                }
-                if start > end {
-                    return Err(format!("Invalid range: {start} is greater than {end}"));
-                }
-
-                for i in start..=end {
-                    let repo = available_repos[i - 1].clone();
-                    if !selected.iter().any(|r: &Repository| r.name == repo.name) {
-                        selected.push(repo);
-                    }
-                }
-            } else {
-                // Handle single number
-                let index: usize = part
-                    .parse()
-                    .map_err(|_| format!("Invalid number: '{part}'"))?;
-
-                if index == 0 {
-                    return Err("Numbers must start from 1".to_string());
-                }
-                if index > max_index {
-                    return Err(format!("Number must be between 1 and {max_index}"));
-                }
+            if let Some(exclusion) = part.strip_prefix('!').or_else(|| part.strip_prefix('^')) {
+                let excluded_indices = Self::resolve_indices(exclusion, max_index)?;
+                let excluded_names: Vec<&str> = excluded_indices
+                    .iter()
+                    .map(|&i| available_repos[i - 1].name.as_str())
+                    .collect();
+                selected.retain(|r| !excluded_names.contains(&r.name.as_str()));
+                continue;
+            }
 
+            for index in Self::resolve_indices(part, max_index)? {
                 let repo = available_repos[index - 1].clone();
                 if !selected.iter().any(|r: &Repository| r.name == repo.name) {
                     selected.push(repo);
@@ -229,7 +352,7 @@ impl InteractiveSelector {
     }
 
     /// Confirm repository selection
-    pub fn confirm_selection(repositories: &[Repository]) -> Result<bool> {
+    pub fn confirm_selection(&self, repositories: &[Repository]) -> Result<bool> {
         if repositories.is_empty() {
             return Ok(false);
         }
@@ -237,10 +360,11 @@ impl InteractiveSelector {
         println!("\nYou have selected {} repositories:", repositories.len());
         for (i, repo) in repositories.iter().enumerate() {
             println!(
-                "  {}. {} ({})",
+                "  {}. {} ({}){}",
                 i + 1,
                 repo.name,
-                Self::format_source(&repo.source)
+                Self::format_source(&repo.source),
+                self.vcs_status_suffix(repo)
             );
         }
 
@@ -261,12 +385,6 @@ impl InteractiveSelector {
     }
 }
 
-impl Default for InteractiveSelector {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,6 +397,11 @@ mod tests {
                 is_private: false,
                 source: "GitHub (user)".to_string(),
                 account: None,
+                category: None,
+                backend: None,
+                topics: Vec::new(),
+                language: None,
+                clone_strategy: None,
             },
             Repository {
                 name: "repo2".to_string(),
@@ -286,6 +409,11 @@ mod tests {
                 is_private: false,
                 source: "GitHub (user)".to_string(),
                 account: None,
+                category: None,
+                backend: None,
+                topics: Vec::new(),
+                language: None,
+                clone_strategy: None,
             },
             Repository {
                 name: "repo3".to_string(),
@@ -293,6 +421,11 @@ mod tests {
                 is_private: false,
                 source: "GitHub (user)".to_string(),
                 account: None,
+                category: None,
+                backend: None,
+                topics: Vec::new(),
+                language: None,
+                clone_strategy: None,
             },
         ]
     }
@@ -363,6 +496,73 @@ mod tests {
         assert!(result.unwrap_err().contains("must be between 1 and 3"));
     }
 
+    #[test]
+    fn test_parse_selection_open_start_range() {
+        let repos = create_test_repos();
+
+        // "-2" means "1 through 2"
+        let result = InteractiveSelector::parse_selection("-2", &repos).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "repo1");
+        assert_eq!(result[1].name, "repo2");
+    }
+
+    #[test]
+    fn test_parse_selection_open_end_range() {
+        let repos = create_test_repos();
+
+        // "2-" means "2 through the last repo"
+        let result = InteractiveSelector::parse_selection("2-", &repos).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "repo2");
+        assert_eq!(result[1].name, "repo3");
+    }
+
+    #[test]
+    fn test_parse_selection_descending_range() {
+        let repos = create_test_repos();
+
+        // "3-1" walks backwards, so the result preserves that order.
+        let result = InteractiveSelector::parse_selection("3-1", &repos).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].name, "repo3");
+        assert_eq!(result[1].name, "repo2");
+        assert_eq!(result[2].name, "repo1");
+    }
+
+    #[test]
+    fn test_parse_selection_exclusion_removes_from_prior_range() {
+        let repos = create_test_repos();
+
+        // "1-3" selects everything, then "!2" drops repo2 back out.
+        let result = InteractiveSelector::parse_selection("1-3,!2", &repos).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "repo1");
+        assert_eq!(result[1].name, "repo3");
+    }
+
+    #[test]
+    fn test_parse_selection_exclusion_with_caret_prefix() {
+        let repos = create_test_repos();
+
+        // '^' is accepted as an alternate exclusion prefix to '!'.
+        let result = InteractiveSelector::parse_selection("all,^1", &repos).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "repo2");
+        assert_eq!(result[1].name, "repo3");
+    }
+
+    #[test]
+    fn test_parse_selection_exclusion_of_not_yet_selected_repo_is_a_no_op() {
+        let repos = create_test_repos();
+
+        // Excluding a repo that was never selected just leaves the
+        // accumulator unchanged, rather than erroring.
+        let result = InteractiveSelector::parse_selection("!2,1", &repos).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "repo1");
+    }
+
     #[test]
     fn test_format_source() {
         assert_eq!(
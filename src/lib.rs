@@ -1,10 +1,24 @@
+pub mod backend;
 pub mod commands;
+pub mod config;
+pub mod credentials;
 pub mod error_handling;
 pub mod git;
 pub mod github;
 pub mod interactive;
 pub mod models;
+pub mod oplog;
+pub mod parallel;
+pub mod picker;
+pub mod providers;
+pub mod rebase_state;
+pub mod recovery;
+pub mod repo_cache;
+pub mod repos_file;
 pub mod search;
+pub mod selector;
+pub mod store;
 pub mod ui;
+pub mod vcs;
 
 pub use models::*;
@@ -1,36 +1,38 @@
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 
+mod backend;
 mod commands;
+mod config;
+mod credentials;
 mod error_handling;
 mod git;
 mod github;
 mod interactive;
 mod models;
+mod oplog;
+mod parallel;
+mod picker;
+mod providers;
+mod rebase_state;
+mod recovery;
+mod repo_cache;
+mod repos_file;
 mod search;
+mod selector;
+mod store;
 mod ui;
+mod vcs;
 
 use commands::workspace;
 use github::GitHubService;
 use interactive::InteractiveSelector;
 
-/// Validate and load repository configuration from JSON file
+/// Validate and load repository configuration from `repos_file`, accepting
+/// either `.viewyard-repos.json` or `.viewyard-repos.toml` (format inferred
+/// from the filename).
 fn load_and_validate_repos(repos_file: &std::path::Path) -> Result<Vec<models::Repository>> {
-    let repos_json = std::fs::read_to_string(repos_file).with_context(|| {
-        format!(
-            "Failed to read configuration file: {}",
-            repos_file.display()
-        )
-    })?;
-
-    let mut repositories: Vec<models::Repository> = serde_json::from_str(&repos_json)
-        .with_context(|| {
-            format!(
-                "Invalid JSON in configuration file: {}\n\
-                Expected format: array of repository objects with 'name', 'url', 'is_private', and 'source' fields",
-                repos_file.display()
-            )
-        })?;
+    let mut repositories = crate::repos_file::load_and_validate(repos_file)?;
 
     // Transform URLs to use SSH host aliases if available
     for repo in &mut repositories {
@@ -41,39 +43,6 @@ fn load_and_validate_repos(repos_file: &std::path::Path) -> Result<Vec<models::R
         }
     }
 
-    // Validate each repository entry
-    for (index, repo) in repositories.iter().enumerate() {
-        if repo.name.trim().is_empty() {
-            anyhow::bail!(
-                "Invalid repository at index {}: 'name' field cannot be empty\n\
-                File: {}",
-                index,
-                repos_file.display()
-            );
-        }
-
-        if repo.url.trim().is_empty() {
-            anyhow::bail!(
-                "Invalid repository at index {}: 'url' field cannot be empty\n\
-                Repository: {}\n\
-                File: {}",
-                index,
-                repo.name,
-                repos_file.display()
-            );
-        }
-
-        // Basic URL validation - should contain git-like patterns
-        if !repo.url.contains("git") && !repo.url.contains("github") && !repo.url.contains("gitlab")
-        {
-            ui::print_warning(&format!(
-                "Repository '{}' has unusual URL format: {}\n\
-                This might not be a valid Git repository URL",
-                repo.name, repo.url
-            ));
-        }
-    }
-
     Ok(repositories)
 }
 
@@ -87,6 +56,23 @@ fn load_and_validate_repos(repos_file: &std::path::Path) -> Result<Vec<models::R
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Git backend to use for workspace operations (shell, gitoxide, auto)
+    #[arg(long, global = true, default_value = "auto")]
+    backend: String,
+
+    /// Preview mutating git operations and viewset file writes without
+    /// running/performing them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Colorize output (auto, always, never)
+    #[arg(long, global = true, default_value = "auto")]
+    color: String,
+
+    /// Output format for commands that support it, e.g. `status` (text, json)
+    #[arg(long, global = true, default_value = "text")]
+    format: String,
 }
 
 #[derive(Subcommand)]
@@ -105,18 +91,67 @@ enum Commands {
 
     // Workspace commands (work from within a view directory)
     /// Show status of all repos in current view
-    Status,
+    Status {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
+        /// Automatically check out the view's branch in repos found on the
+        /// wrong branch, creating it from the default branch if needed.
+        /// Repos with uncommitted changes are skipped rather than touched.
+        #[arg(long)]
+        fix: bool,
+        /// Number of repos to check concurrently (default: available parallelism)
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
     /// Rebase repos against their default branch
-    Rebase,
+    Rebase {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view. Ignored with `--continue`/`--abort`.
+        #[arg(long)]
+        select: Option<String>,
+        /// Resume a rebase batch that previously stopped on a conflict
+        #[arg(long = "continue")]
+        continue_: bool,
+        /// Abort the repo the batch stopped on and discard rebase progress
+        #[arg(long)]
+        abort: bool,
+    },
     /// Commit to all dirty repos (only repos with changes)
     #[command(name = "commit-all")]
     CommitAll {
         /// Commit message
         message: String,
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
     },
     /// Push repos with commits ahead (only repos with unpushed commits)
     #[command(name = "push-all")]
-    PushAll,
+    PushAll {
+        /// Revset-style expression selecting which repos to act on, e.g.
+        /// `dirty | ahead` or `!private & name(service-*)`. Omit to select
+        /// every repo in the view.
+        #[arg(long)]
+        select: Option<String>,
+    },
+    /// Continuously re-poll every repo in the current view, reporting what changed
+    Watch {
+        /// Seconds to sleep between polls
+        #[arg(long, default_value_t = 30)]
+        interval: u64,
+    },
+    /// Undo the most recent rebase, commit-all, or push-all
+    Undo {
+        /// Restore repos even if they have uncommitted changes
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -125,15 +160,106 @@ enum ViewsetCommand {
     Create {
         /// Name of the viewset directory to create
         name: String,
-        /// GitHub account to search repositories from
+        /// Account to search repositories from: a bare GitHub username, or
+        /// a forge-qualified form like `gitlab:mygroup` or
+        /// `gitea:myorg@git.example.com` to discover from a self-hosted
+        /// GitLab/Gitea/Forgejo instance
         #[arg(long)]
         account: Option<String>,
+        /// Gitignore-style include/exclude glob patterns (repeatable; prefix
+        /// with `!` to exclude), e.g. `--filter 'service-*' --filter '!*-deprecated'`
+        #[arg(long = "filter")]
+        filters: Vec<String>,
+        /// Repository list format to write: json (default) or toml
+        #[arg(long, default_value = "json")]
+        format: String,
+        /// Select from the last cached repository snapshot instead of
+        /// re-discovering from the network
+        #[arg(long)]
+        offline: bool,
+        /// Clone selected repos at this depth (`git clone --depth N`)
+        /// instead of full history
+        #[arg(long)]
+        depth: Option<u32>,
+        /// Clone selected repos with only the view's branch, not every
+        /// branch the repo has
+        #[arg(long)]
+        single_branch: bool,
+        /// Clone selected repos as blobless partial clones
+        /// (`--filter=blob:none`), fetching file contents on demand
+        #[arg(long)]
+        blobless: bool,
     },
     /// Update an existing viewset by adding new repositories
     Update {
-        /// GitHub account to search repositories from
+        /// Account to search repositories from: a bare GitHub username, or
+        /// a forge-qualified form like `gitlab:mygroup` or
+        /// `gitea:myorg@git.example.com` to discover from a self-hosted
+        /// GitLab/Gitea/Forgejo instance
+        #[arg(short, long)]
+        account: Option<String>,
+        /// Select from the last cached repository snapshot instead of
+        /// re-discovering from the network
+        #[arg(long)]
+        offline: bool,
+        /// Skip the per-repo ahead/behind/dirty status column next to
+        /// existing repositories - faster for viewsets with many clones
+        #[arg(long)]
+        no_status: bool,
+        /// Only offer repos tagged with this topic (case-insensitive, exact match)
+        #[arg(long)]
+        topic: Option<String>,
+        /// Only offer repos whose primary language matches (case-insensitive, exact match)
+        #[arg(long)]
+        language: Option<String>,
+        /// Only offer repos whose name or source fuzzy-matches this keyword
+        #[arg(long)]
+        search: Option<String>,
+        /// Clone newly added repos at this depth (`git clone --depth N`)
+        /// instead of full history
+        #[arg(long)]
+        depth: Option<u32>,
+        /// Clone newly added repos with only the view's branch, not every
+        /// branch the repo has
+        #[arg(long)]
+        single_branch: bool,
+        /// Clone newly added repos as blobless partial clones
+        /// (`--filter=blob:none`), fetching file contents on demand
+        #[arg(long)]
+        blobless: bool,
+    },
+    /// Sync a viewset with a forge org/group, adding any repositories it has
+    /// that aren't already present; or, with no target, reconcile every view
+    /// against the manifest instead of a remote forge
+    Sync {
+        /// Sync target of the form `<forge>:<org>`, e.g. `github:acme` or
+        /// `gitlab:my-group`. Omit to reconcile existing views against
+        /// `.viewyard-repos.json` instead of a remote forge.
+        spec: Option<String>,
+        /// With no `spec`: clone missing repos into each view and append
+        /// discovered unmanaged repos back into the manifest. Ignored when
+        /// `spec` is given.
+        #[arg(long)]
+        fix: bool,
+        /// With no `spec`: skip the per-repo ahead/behind/dirty status line
+        /// for each view's repos - faster for viewsets with many views.
+        /// Ignored when `spec` is given.
+        #[arg(long)]
+        no_status: bool,
+    },
+    /// Review a viewset's repositories and remove entries that were
+    /// archived, renamed, or deleted upstream
+    Prune {
+        /// Account to cross-check against: a bare GitHub username, or a
+        /// forge-qualified form like `gitlab:mygroup` or
+        /// `gitea:myorg@git.example.com`. Repos no longer found there are
+        /// flagged, but every repo stays selectable for removal regardless.
         #[arg(short, long)]
         account: Option<String>,
+        /// Select from the last cached repository snapshot instead of
+        /// re-discovering from the network
+        #[arg(long)]
+        offline: bool,
     },
 }
 
@@ -143,46 +269,150 @@ enum ViewCommand {
     Create {
         /// Name of the view/branch to create
         name: String,
+        /// Number of repos to clone/set up concurrently (default: available parallelism)
+        #[arg(long)]
+        jobs: Option<usize>,
     },
     /// Update an existing view to include new repositories from the viewset
     Update {
         /// Name of the view to update (defaults to current view)
         name: Option<String>,
+        /// Number of repos to clone/set up concurrently (default: available parallelism)
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Remove a view's directory and reclaim its worktrees in the shared mirror store
+    Remove {
+        /// Name of the view to remove (defaults to current view)
+        name: Option<String>,
     },
 }
 
 fn main() -> Result<()> {
+    // Hidden askpass-helper mode: git/ssh re-invoke this binary as
+    // $GIT_ASKPASS/$SSH_ASKPASS with the prompt text as argv[1], so this must
+    // be checked before clap ever sees argv. See `git::configure_credential_env`.
+    if std::env::var_os(git::ASKPASS_HELPER_ENV).is_some() {
+        let prompt_text = std::env::args().nth(1).unwrap_or_default();
+        let answer = git::run_askpass_helper(&prompt_text, &git::TerminalPromptHandler)?;
+        println!("{answer}");
+        return Ok(());
+    }
+
     let cli = Cli::parse();
+    let backend_kind = backend::BackendKind::parse(&cli.backend)?;
+
+    if cli.dry_run {
+        git::set_exec_mode(git::ExecMode::DryRun);
+    }
+    ui::set_color_mode(ui::ColorMode::parse(&cli.color)?);
+    ui::set_output_format(ui::OutputFormat::parse(&cli.format)?);
 
     match cli.command {
         Commands::Viewset { action } => handle_viewset_command(action),
         Commands::View { action } => handle_view_command(action),
 
         // Workspace commands
-        Commands::Status => workspace::handle_command(workspace::WorkspaceCommand::Status),
-        Commands::Rebase => workspace::handle_command(workspace::WorkspaceCommand::Rebase),
-        Commands::CommitAll { message } => {
-            workspace::handle_command(workspace::WorkspaceCommand::CommitAll { message })
-        }
-        Commands::PushAll => workspace::handle_command(workspace::WorkspaceCommand::PushAll),
+        Commands::Status { select, fix, jobs } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::Status { select, fix, jobs },
+            backend_kind,
+        ),
+        Commands::Rebase {
+            select,
+            continue_,
+            abort,
+        } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::Rebase {
+                select,
+                continue_,
+                abort,
+            },
+            backend_kind,
+        ),
+        Commands::CommitAll { message, select } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::CommitAll { message, select },
+            backend_kind,
+        ),
+        Commands::PushAll { select } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::PushAll { select },
+            backend_kind,
+        ),
+        Commands::Watch { interval } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::Watch { interval },
+            backend_kind,
+        ),
+        Commands::Undo { force } => workspace::handle_command_with_backend(
+            workspace::WorkspaceCommand::Undo { force },
+            backend_kind,
+        ),
     }
 }
 
 fn handle_viewset_command(action: ViewsetCommand) -> Result<()> {
     match action {
-        ViewsetCommand::Create { name, account } => create_viewset(&name, account.as_deref()),
-        ViewsetCommand::Update { account } => update_viewset(account.as_deref()),
+        ViewsetCommand::Create {
+            name,
+            account,
+            filters,
+            format,
+            offline,
+            depth,
+            single_branch,
+            blobless,
+        } => {
+            let format = match format.as_str() {
+                "json" => repos_file::RepoFileFormat::Json,
+                "toml" => repos_file::RepoFileFormat::Toml,
+                other => anyhow::bail!("Unknown repository list format '{other}'; expected 'json' or 'toml'"),
+            };
+            let clone_strategy = models::CloneStrategy { depth, single_branch, blobless };
+            create_viewset(&name, account.as_deref(), &filters, format, offline, clone_strategy)
+        }
+        ViewsetCommand::Update {
+            account,
+            offline,
+            no_status,
+            topic,
+            language,
+            search,
+            depth,
+            single_branch,
+            blobless,
+        } => update_viewset(
+            account.as_deref(),
+            offline,
+            no_status,
+            search::DiscoveryFilter::new(topic, language, search),
+            models::CloneStrategy { depth, single_branch, blobless },
+        ),
+        ViewsetCommand::Sync {
+            spec,
+            fix,
+            no_status,
+        } => match spec {
+            Some(spec) => sync_viewset(&spec),
+            None => reconcile_viewset(fix, no_status),
+        },
+        ViewsetCommand::Prune { account, offline } => prune_viewset(account.as_deref(), offline),
     }
 }
 
 fn handle_view_command(action: ViewCommand) -> Result<()> {
     match action {
-        ViewCommand::Create { name } => create_view(&name),
-        ViewCommand::Update { name } => update_view(name.as_deref()),
+        ViewCommand::Create { name, jobs } => create_view(&name, jobs),
+        ViewCommand::Update { name, jobs } => update_view(name.as_deref(), jobs),
+        ViewCommand::Remove { name } => remove_view(name.as_deref()),
     }
 }
 
-fn create_viewset(name: &str, account: Option<&str>) -> Result<()> {
+fn create_viewset(
+    name: &str,
+    account: Option<&str>,
+    filters: &[String],
+    format: repos_file::RepoFileFormat,
+    offline: bool,
+    clone_strategy: models::CloneStrategy,
+) -> Result<()> {
     ui::print_info(&format!("Creating viewset: {name}"));
 
     // Check if git is available
@@ -199,25 +429,49 @@ fn create_viewset(name: &str, account: Option<&str>) -> Result<()> {
     }
 
     // Discover repositories
-    let Ok(repositories) = discover_repositories_for_viewset(account) else {
-        return create_empty_viewset(&viewset_path, name, "when GitHub CLI is set up");
+    let Ok(mut repositories) = discover_repositories_for_viewset(account, offline) else {
+        return create_empty_viewset(&viewset_path, name, "when GitHub CLI is set up", format);
     };
 
+    if !filters.is_empty() {
+        let repo_filter = search::RepoFilter::new(filters)?;
+        repositories = repo_filter.filter(&repositories);
+    }
+
     // Interactive repository selection
     let selector = InteractiveSelector::new();
     let selected_repos = selector.select_repositories(&repositories)?;
 
     if selected_repos.is_empty() {
         ui::print_info("No repositories selected. Creating empty viewset.");
-        return create_empty_viewset(&viewset_path, name, "later");
+        return create_empty_viewset(&viewset_path, name, "later", format);
     }
 
     // Confirm selection
-    if !InteractiveSelector::confirm_selection(&selected_repos)? {
+    if !selector.confirm_selection(&selected_repos)? {
         ui::print_info("Repository selection cancelled.");
         return Ok(());
     }
 
+    let mut selected_repos = selected_repos;
+    if !clone_strategy.is_default() {
+        for repo in &mut selected_repos {
+            repo.clone_strategy = Some(clone_strategy);
+        }
+    }
+
+    // Store repository list for the viewset
+    let repos_file_path = viewset_path.join(format.filename());
+    let selected_repos = repos_file::canonicalize_repos(selected_repos);
+    let serialized = repos_file::serialize_repos(&selected_repos, format)?;
+
+    if git::exec_mode() == git::ExecMode::DryRun {
+        ui::print_info(&format!("Would create viewset directory: {}", viewset_path.display()));
+        ui::print_info(&format!("Would write to {}:", repos_file_path.display()));
+        print!("{}", repos_file::diff_lines("", &serialized));
+        return Ok(());
+    }
+
     // Create viewset directory
     std::fs::create_dir_all(&viewset_path)?;
     ui::print_success(&format!(
@@ -225,10 +479,7 @@ fn create_viewset(name: &str, account: Option<&str>) -> Result<()> {
         viewset_path.display()
     ));
 
-    // Store repository list for the viewset
-    let repos_file = viewset_path.join(".viewyard-repos.json");
-    let repos_json = serde_json::to_string_pretty(&selected_repos)?;
-    std::fs::write(&repos_file, repos_json)?;
+    std::fs::write(&repos_file_path, serialized)?;
 
     ui::print_success(&format!(
         "Viewset '{}' created successfully with {} repositories!",
@@ -242,31 +493,38 @@ fn create_viewset(name: &str, account: Option<&str>) -> Result<()> {
 }
 
 /// Create an empty viewset directory with helpful instructions
-fn create_empty_viewset(viewset_path: &std::path::Path, name: &str, when: &str) -> Result<()> {
+fn create_empty_viewset(
+    viewset_path: &std::path::Path,
+    name: &str,
+    when: &str,
+    format: repos_file::RepoFileFormat,
+) -> Result<()> {
     std::fs::create_dir_all(viewset_path)?;
     ui::print_success(&format!(
         "✓ Created empty viewset directory: {}",
         viewset_path.display()
     ));
     ui::print_info(&format!("Navigate to: cd {name}"));
-    ui::print_info(&format!("Manually edit .viewyard-repos.json to add repositories {when}"));
+    ui::print_info(&format!(
+        "Manually edit {} to add repositories {when}",
+        format.filename()
+    ));
     ui::print_info("Then run 'viewyard view create <view-name>' to create your first view");
     Ok(())
 }
 
 /// Load repositories from a viewset with validation
 fn load_viewset_repositories(viewset_root: &std::path::Path) -> Result<Vec<models::Repository>> {
-    let repos_file = viewset_root.join(".viewyard-repos.json");
-    if !repos_file.exists() {
+    let Some((repos_file, _format)) = repos_file::find_repos_file(viewset_root) else {
         ui::show_error_with_help(
             "No repositories found in this viewset",
             &[
-                "Manually edit .viewyard-repos.json to add repositories to this viewset",
+                "Manually edit .viewyard-repos.json (or .viewyard-repos.toml) to add repositories to this viewset",
                 "Or create a new viewset with 'viewyard viewset create <name>'",
             ],
         );
         anyhow::bail!("No repositories in viewset");
-    }
+    };
 
     let repositories = load_and_validate_repos(&repos_file)?;
 
@@ -281,12 +539,9 @@ fn load_viewset_repositories(viewset_root: &std::path::Path) -> Result<Vec<model
     Ok(repositories)
 }
 
-fn create_view(view_name: &str) -> Result<()> {
+fn create_view(view_name: &str, jobs: Option<usize>) -> Result<()> {
     ui::print_info(&format!("Creating view: {view_name}"));
 
-    // Check if git is available
-    git::check_git_availability()?;
-
     // Detect viewset context
     let viewset_context = detect_viewset_context()?;
     let view_path = viewset_context.viewset_root.join(view_name);
@@ -302,6 +557,20 @@ fn create_view(view_name: &str) -> Result<()> {
     // Load repository list from viewset
     let repositories = load_viewset_repositories(&viewset_context.viewset_root)?;
 
+    // Check that every VCS backend this viewset's repos declare is available
+    vcs::check_backends_available(&repositories)?;
+
+    if git::exec_mode() == git::ExecMode::DryRun {
+        ui::print_info(&format!(
+            "would create view '{view_name}' with {} repositories:",
+            repositories.len()
+        ));
+        for repo in &repositories {
+            ui::print_info(&format!("  would set up {} on branch '{view_name}'", repo.name));
+        }
+        return Ok(());
+    }
+
     // Create temporary directory for atomic operation
     let temp_view_path = view_path.with_extension("tmp");
 
@@ -316,38 +585,68 @@ fn create_view(view_name: &str) -> Result<()> {
         temp_view_path.display()
     ));
 
-    // Clone repositories and create/checkout branches to temporary directory
+    // Clone repositories and create/checkout branches to temporary directory,
+    // concurrently - each repo's clone/checkout is independent of the others,
+    // so there's no reason to serialize them behind one another.
     ui::print_info("Cloning repositories and setting up branches...");
 
-    // Track success for cleanup on failure
-
-    for repo in &repositories {
+    let worker_count = parallel::resolve_worker_count(repositories.len(), jobs);
+    let viewset_root_for_workers = viewset_context.viewset_root.clone();
+    let temp_view_path_for_workers = temp_view_path.clone();
+    let progress = ui::Progress::new(repositories.len());
+    let preferred_auth = config::auth_method_for_viewset_root(&viewset_context.viewset_root);
+    let results = parallel::parallel_map(repositories.clone(), worker_count, move |repo| {
         ui::print_info(&format!(
             "  Setting up {} on branch '{}'",
             repo.name, view_name
         ));
+        let result = clone_and_setup_branch(
+            &repo,
+            &viewset_root_for_workers,
+            &temp_view_path_for_workers,
+            view_name,
+            &progress,
+            preferred_auth,
+        );
+        (repo.name, result)
+    });
 
-        match clone_and_setup_branch(repo, &temp_view_path, view_name) {
-            Ok(()) => {
-                // Repository cloned successfully
-            }
-            Err(e) => {
-                // Cleanup temporary directory on any failure
-                ui::print_error(&format!("Failed to setup {}: {}", repo.name, e));
-                ui::print_info("Cleaning up temporary files...");
-                if let Err(cleanup_err) = std::fs::remove_dir_all(&temp_view_path) {
-                    ui::print_warning(&format!(
-                        "Failed to cleanup temporary directory: {cleanup_err}"
-                    ));
-                }
-                return Err(e.context(format!("Failed to setup repository '{}'", repo.name)));
-            }
+    let failures: Vec<(String, anyhow::Error)> =
+        results.into_iter().filter_map(|(name, result)| result.err().map(|e| (name, e))).collect();
+
+    if !failures.is_empty() {
+        for (name, e) in &failures {
+            ui::print_error(&format!("Failed to setup {name}: {e}"));
+        }
+        ui::print_info("Cleaning up temporary files...");
+        if let Err(cleanup_err) = std::fs::remove_dir_all(&temp_view_path) {
+            ui::print_warning(&format!(
+                "Failed to cleanup temporary directory: {cleanup_err}"
+            ));
         }
+        let (failed_repo, first_error) = failures.into_iter().next().unwrap();
+        return Err(first_error.context(format!("Failed to setup repository '{failed_repo}'")));
     }
 
     // All operations succeeded - atomically move temp directory to final location
     std::fs::rename(&temp_view_path, &view_path).context("Failed to finalize view creation")?;
 
+    // Worktrees record the absolute path they were created at, so the
+    // temp-directory rename above leaves each mirror's worktree admin files
+    // pointing at the old (now-gone) path; `worktree repair` re-points them
+    // at the repo's final location.
+    for repo in &repositories {
+        if vcs::Backend::from_repo(repo) == vcs::Backend::Git {
+            let repo_path = view_path.join(&repo.name);
+            if let Err(e) = store::repair_worktree(&repo_path) {
+                ui::print_warning(&format!(
+                    "Failed to repair worktree path for {}: {e}",
+                    repo.name
+                ));
+            }
+        }
+    }
+
     ui::print_success(&format!(
         "View '{}' created successfully with {} repositories!",
         view_name,
@@ -369,18 +668,16 @@ struct ViewsetContext {
 fn detect_viewset_context() -> Result<ViewsetContext> {
     let current_dir = std::env::current_dir()?;
 
-    // Check if current directory is a viewset root (contains .viewyard-repos.json)
-    let repos_file = current_dir.join(".viewyard-repos.json");
-    if repos_file.exists() {
+    // Check if current directory is a viewset root (contains a repos file)
+    if repos_file::find_repos_file(&current_dir).is_some() {
         return Ok(ViewsetContext {
             viewset_root: current_dir,
         });
     }
 
-    // Check if current directory is a view (parent contains .viewyard-repos.json)
+    // Check if current directory is a view (parent contains a repos file)
     if let Some(parent) = current_dir.parent() {
-        let repos_file = parent.join(".viewyard-repos.json");
-        if repos_file.exists() {
+        if repos_file::find_repos_file(parent).is_some() {
             return Ok(ViewsetContext {
                 viewset_root: parent.to_path_buf(),
             });
@@ -394,10 +691,10 @@ fn detect_viewset_context() -> Result<ViewsetContext> {
         "Viewset commands must be run from within a viewset directory",
         &[
             &format!("Current directory: {current_path}"),
-            "Expected: directory containing .viewyard-repos.json",
+            "Expected: directory containing .viewyard-repos.json or .viewyard-repos.toml",
             "Create a viewset: viewyard viewset create my-project",
             "Then navigate: cd my-project",
-            "List existing viewsets: find . -maxdepth 2 -name '.viewyard-repos.json' -exec dirname {} \\;",
+            "List existing viewsets: find . -maxdepth 2 -name '.viewyard-repos.*' -exec dirname {} \\;",
         ],
     );
     Err(anyhow::anyhow!("Not in a viewset directory"))
@@ -405,84 +702,64 @@ fn detect_viewset_context() -> Result<ViewsetContext> {
 
 fn clone_and_setup_branch(
     repo: &models::Repository,
+    viewset_root: &std::path::Path,
     view_path: &std::path::Path,
     branch_name: &str,
+    progress: &ui::Progress,
+    preferred_auth: credentials::AuthMethod,
 ) -> Result<()> {
     let repo_path = view_path.join(&repo.name);
+    let backend = vcs::Backend::from_repo(repo);
+    let vcs_backend = backend.resolve()?;
 
-    // Clone repository (full clone for complete git functionality)
-    let output = std::process::Command::new("git")
-        .args(["clone", &repo.url, &repo.name])
-        .current_dir(view_path)
-        .output()
-        .context("Failed to execute git clone")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        error_handling::handle_clone_error(&repo.name, &stderr)?;
+    if git::exec_mode() == git::ExecMode::DryRun {
+        ui::print_info(&format!(
+            "would set up {} on branch '{branch_name}'",
+            repo.name
+        ));
+        return Ok(());
     }
 
+    // Materialize the repo on `branch_name` - a cheap worktree backed by a
+    // shared mirror for backends that support it, a full clone otherwise.
+    let outcome = vcs_backend.setup_worktree(
+        &repo.url,
+        viewset_root,
+        view_path,
+        &repo.name,
+        branch_name,
+        repo.clone_strategy.as_ref(),
+        Some(&|update| progress.report(&repo.name, &update)),
+        preferred_auth,
+    )?;
+    progress.repo_completed(&repo.name);
     ui::print_info(&format!("  Cloned {}", repo.name));
 
-    // Configure git user identity for this repository
-    git::validate_repository_for_operations(&repo_path, repo)
-        .with_context(|| format!("Failed to configure git user for repository: {}", repo.name))?;
+    if backend == vcs::Backend::Git {
+        // Configure git user identity for this repository
+        git::validate_repository_for_operations(&repo_path, repo)
+            .with_context(|| format!("Failed to configure git user for repository: {}", repo.name))?;
+    }
 
-    // Create and checkout branch
-    setup_branch_in_repo(&repo_path, branch_name)?;
+    print_branch_setup_outcome(branch_name, outcome);
 
     Ok(())
 }
 
-fn setup_branch_in_repo(repo_path: &std::path::Path, branch_name: &str) -> Result<()> {
-    // Check if branch already exists
-    let check_output = std::process::Command::new("git")
-        .args(["branch", "--list", branch_name])
-        .current_dir(repo_path)
-        .output()
-        .context("Failed to check if branch exists")?;
-
-    let branch_exists = !String::from_utf8_lossy(&check_output.stdout)
-        .trim()
-        .is_empty();
-
-    if branch_exists {
-        // Checkout existing branch
-        let output = std::process::Command::new("git")
-            .args(["checkout", branch_name])
-            .current_dir(repo_path)
-            .output()
-            .context("Failed to checkout existing branch")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error_handling::handle_checkout_error(branch_name, repo_path, &stderr)?;
+fn print_branch_setup_outcome(branch_name: &str, outcome: vcs::BranchSetupOutcome) {
+    match outcome {
+        vcs::BranchSetupOutcome::CheckedOutExisting => {
+            ui::print_info(&format!("    Checked out existing branch '{branch_name}'"));
         }
-        ui::print_info(&format!("    Checked out existing branch '{branch_name}'"));
-    } else {
-        // Create new branch from current default branch
-        let output = std::process::Command::new("git")
-            .args(["checkout", "-b", branch_name])
-            .current_dir(repo_path)
-            .output()
-            .context("Failed to create new branch")?;
-
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            error_handling::handle_branch_creation_error(branch_name, repo_path, &stderr)?;
+        vcs::BranchSetupOutcome::Created => {
+            ui::print_info(&format!(
+                "    Created and checked out new branch '{branch_name}'"
+            ));
         }
-        ui::print_info(&format!(
-            "    Created and checked out new branch '{branch_name}'"
-        ));
     }
-
-    Ok(())
 }
 
-fn update_view(view_name: Option<&str>) -> Result<()> {
-    // Check if git is available
-    git::check_git_availability()?;
-
+fn update_view(view_name: Option<&str>, jobs: Option<usize>) -> Result<()> {
     // Detect viewset context
     let viewset_context = detect_viewset_context()?;
 
@@ -520,6 +797,9 @@ fn update_view(view_name: Option<&str>) -> Result<()> {
         return Ok(());
     };
 
+    // Check that every VCS backend this viewset's repos declare is available
+    vcs::check_backends_available(&repositories)?;
+
     // Determine which repositories are missing from the current view
     let missing_repos = find_missing_repositories(&repositories, &view_path);
 
@@ -538,26 +818,46 @@ fn update_view(view_name: Option<&str>) -> Result<()> {
             .join(", ")
     ));
 
-    // Clone and setup missing repositories directly in the view
+    // Clone and setup missing repositories directly in the view, concurrently
     ui::print_info("Adding missing repositories...");
 
-    for repo in &missing_repos {
+    let worker_count = parallel::resolve_worker_count(missing_repos.len(), jobs);
+    let viewset_root_for_workers = viewset_context.viewset_root.clone();
+    let view_path_for_workers = view_path.clone();
+    let target_view_name_for_workers = target_view_name.clone();
+    let progress = ui::Progress::new(missing_repos.len());
+    let preferred_auth = config::auth_method_for_viewset_root(&viewset_context.viewset_root);
+    let results = parallel::parallel_map(missing_repos.clone(), worker_count, move |repo| {
         ui::print_info(&format!(
             "  Setting up {} on branch '{}'",
-            repo.name, target_view_name
+            repo.name, target_view_name_for_workers
         ));
+        let result = clone_and_setup_repository_in_view(
+            &repo,
+            &viewset_root_for_workers,
+            &view_path_for_workers,
+            &target_view_name_for_workers,
+            &progress,
+            preferred_auth,
+        );
+        (repo.name, result)
+    });
 
-        match clone_and_setup_repository_in_view(repo, &view_path, &target_view_name) {
-            Ok(()) => {
-                ui::print_info(&format!("  ✓ Added {}", repo.name));
-            }
+    let mut failed = Vec::new();
+    for (name, result) in results {
+        match result {
+            Ok(()) => ui::print_info(&format!("  ✓ Added {name}")),
             Err(e) => {
-                ui::print_error(&format!("Failed to add {}: {}", repo.name, e));
-                return Err(e.context(format!("Failed to add repository '{}'", repo.name)));
+                ui::print_error(&format!("Failed to add {name}: {e}"));
+                failed.push(name);
             }
         }
     }
 
+    if !failed.is_empty() {
+        anyhow::bail!("Failed to add {} repositories: {}", failed.len(), failed.join(", "));
+    }
+
     ui::print_success(&format!(
         "View '{}' updated successfully! Added {} repositories.",
         target_view_name,
@@ -567,6 +867,46 @@ fn update_view(view_name: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Remove a view's directory and prune the worktree references it leaves
+/// behind in the viewset's shared mirror store.
+fn remove_view(view_name: Option<&str>) -> Result<()> {
+    // Detect viewset context
+    let viewset_context = detect_viewset_context()?;
+
+    // Determine view name - use provided name or detect from current directory
+    let target_view_name = if let Some(name) = view_name {
+        name.to_string()
+    } else {
+        std::env::current_dir()?
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| anyhow::anyhow!("Could not determine view name from current directory"))?
+            .to_string()
+    };
+
+    let view_path = viewset_context.viewset_root.join(&target_view_name);
+
+    if !view_path.exists() {
+        ui::show_error_with_help(
+            &format!("View '{target_view_name}' does not exist"),
+            &["List existing views: ls <viewset-root>"],
+        );
+        return Err(anyhow::anyhow!("View does not exist"));
+    }
+
+    std::fs::remove_dir_all(&view_path)
+        .with_context(|| format!("Failed to remove view directory: {}", view_path.display()))?;
+
+    // The view's worktrees are gone from disk but each repo's mirror still
+    // carries a reference to them until pruned.
+    store::prune_mirrors(&viewset_context.viewset_root)
+        .context("Failed to prune worktree references after removing view")?;
+
+    ui::print_success(&format!("View '{target_view_name}' removed"));
+
+    Ok(())
+}
+
 /// Find repositories that are missing from the current view
 fn find_missing_repositories(
     all_repos: &[models::Repository],
@@ -587,8 +927,11 @@ fn find_missing_repositories(
 /// Clone and setup a single repository directly in an existing view
 fn clone_and_setup_repository_in_view(
     repo: &models::Repository,
+    viewset_root: &std::path::Path,
     view_path: &std::path::Path,
     branch_name: &str,
+    progress: &ui::Progress,
+    preferred_auth: credentials::AuthMethod,
 ) -> Result<()> {
     let repo_path = view_path.join(&repo.name);
 
@@ -597,75 +940,110 @@ fn clone_and_setup_repository_in_view(
         return Ok(()); // Already exists, nothing to do
     }
 
-    // Clone repository directly into the view
-    let output = std::process::Command::new("git")
-        .args(["clone", &repo.url, &repo.name])
-        .current_dir(view_path)
-        .output()
-        .context("Failed to execute git clone")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-
-        // Provide specific recovery guidance based on error type
-        if stderr.contains("Permission denied") || stderr.contains("publickey") {
-            ui::print_error(&format!("SSH authentication failed for {}", repo.name));
-            ui::print_info("SSH key issues detected:");
-            ui::print_info("   • Test SSH connection: ssh -T git@github.com");
-            ui::print_info(
-                "   • Add SSH key to GitHub: gh auth refresh -h github.com -s admin:public_key",
-            );
-            anyhow::bail!("SSH authentication failed for repository '{}'", repo.name);
-        } else if stderr.contains("not found") || stderr.contains("does not exist") {
-            ui::print_error(&format!("Repository not found: {}", repo.name));
-            ui::print_info("Repository access issues:");
-            ui::print_info(&format!(
-                "   • Verify repository exists: gh repo view {}",
-                repo.name
-            ));
-            ui::print_info("   • Check repository URL in .viewyard-repos.json");
-            ui::print_info("   • Ensure you have access to this repository");
-            anyhow::bail!("Repository '{}' not found or inaccessible", repo.name);
-        }
+    let backend = vcs::Backend::from_repo(repo);
+    let vcs_backend = backend.resolve()?;
 
-        anyhow::bail!("Failed to clone repository '{}': {}", repo.name, stderr);
+    if git::exec_mode() == git::ExecMode::DryRun {
+        ui::print_info(&format!(
+            "would set up {} on branch '{branch_name}'",
+            repo.name
+        ));
+        return Ok(());
     }
 
-    // Configure git user for the repository
-    if let Some(ref account) = repo.account {
-        git::validate_and_configure_git_user(&repo_path, account)?;
-    } else if let Ok(account) = git::extract_account_from_source(&repo.source) {
-        git::validate_and_configure_git_user(&repo_path, &account)?;
+    // Materialize the repo directly into the view, on `branch_name`
+    let outcome = vcs_backend.setup_worktree(
+        &repo.url,
+        viewset_root,
+        view_path,
+        &repo.name,
+        branch_name,
+        repo.clone_strategy.as_ref(),
+        Some(&|update| progress.report(&repo.name, &update)),
+        preferred_auth,
+    )?;
+    progress.repo_completed(&repo.name);
+
+    if backend == vcs::Backend::Git {
+        // Configure git user for the repository
+        let account = repo
+            .account
+            .clone()
+            .or_else(|| git::extract_account_from_source(&repo.source).ok());
+        if let Some(account) = account {
+            git::validate_and_configure_git_user(&repo_path, &account)?;
+
+            let ssh_identities = git::load_ssh_identities_config().unwrap_or_default();
+            let key_path = ssh_identities
+                .accounts
+                .get(&account)
+                .map(|identity| identity.private.as_str());
+            git::configure_ssh_identity(&repo_path, key_path)?;
+        }
     }
 
     // Setup branch in the newly cloned repository
-    setup_branch_in_repo(&repo_path, branch_name)?;
+    print_branch_setup_outcome(branch_name, outcome);
 
     Ok(())
 }
 
-/// Discover repositories from GitHub based on account preference
-fn discover_repositories_for_viewset(account: Option<&str>) -> Result<Vec<models::Repository>> {
-    // Check GitHub CLI availability
-    if !GitHubService::check_availability()? {
-        ui::show_error_with_help(
-            "GitHub CLI is not available or not authenticated",
-            &[
-                "Install GitHub CLI: https://cli.github.com/",
-                "Then authenticate: gh auth login",
-                "Or manually edit .viewyard-repos.json to add repositories",
-            ],
-        );
-        anyhow::bail!("GitHub CLI not available");
+/// Discover repositories from GitHub based on account preference, or load the
+/// last cached snapshot instead of touching the network when `offline` is set.
+fn discover_repositories_for_viewset(
+    account: Option<&str>,
+    offline: bool,
+) -> Result<Vec<models::Repository>> {
+    if offline {
+        let (repositories, age_secs) = repo_cache::load_snapshot()?;
+        ui::print_info(&format!(
+            "Using cached repository snapshot ({}, {} repositories)",
+            repo_cache::format_age(age_secs),
+            repositories.len()
+        ));
+        return Ok(repositories);
     }
 
-    // Discover repositories
-    ui::print_info("Discovering repositories from GitHub...");
-
-    let repositories = if let Some(specific_account) = account {
-        GitHubService::discover_repositories_from_account(specific_account)?
+    // A bare account targets GitHub via its existing CLI-backed path; a
+    // forge-qualified account (`gitlab:mygroup`, `gitea:org@host`) routes to
+    // the matching `RepositoryProvider` instead.
+    let repositories = if let Some(specific_account) = account.filter(|a| a.contains(':')) {
+        let forge_account = providers::parse_forge_account(specific_account)?;
+        if !forge_account.provider.check_availability()? {
+            anyhow::bail!(
+                "{} is not reachable or not authenticated; check its token/URL environment variables",
+                forge_account.provider.name()
+            );
+        }
+        ui::print_info(&format!(
+            "Discovering repositories from {}'s '{}' account...",
+            forge_account.provider.name(),
+            forge_account.account
+        ));
+        forge_account
+            .provider
+            .discover_repositories_from_account(&forge_account.account)?
     } else {
-        GitHubService::discover_all_repositories()?
+        // Check GitHub CLI availability
+        if !GitHubService::check_availability()? {
+            ui::show_error_with_help(
+                "GitHub CLI is not available or not authenticated",
+                &[
+                    "Install GitHub CLI: https://cli.github.com/",
+                    "Then authenticate: gh auth login",
+                    "Or manually edit .viewyard-repos.json to add repositories",
+                ],
+            );
+            anyhow::bail!("GitHub CLI not available");
+        }
+
+        ui::print_info("Discovering repositories from GitHub...");
+
+        if let Some(specific_account) = account {
+            GitHubService::discover_repositories_from_account(specific_account)?
+        } else {
+            GitHubService::discover_all_repositories()?
+        }
     };
 
     if repositories.is_empty() {
@@ -673,6 +1051,10 @@ fn discover_repositories_for_viewset(account: Option<&str>) -> Result<Vec<models
         anyhow::bail!("No repositories found");
     }
 
+    if let Err(e) = repo_cache::save_snapshot(&repositories) {
+        ui::print_warning(&format!("Failed to cache repository snapshot: {e}"));
+    }
+
     Ok(repositories)
 }
 
@@ -695,6 +1077,7 @@ fn filter_existing_repositories(
 fn select_repositories_for_update(
     available_repos: &[models::Repository],
     existing_repos: &[models::Repository],
+    show_status: bool,
 ) -> Result<Vec<models::Repository>> {
     if available_repos.is_empty() {
         ui::print_info("All available repositories are already in the viewset.");
@@ -715,8 +1098,11 @@ fn select_repositories_for_update(
         println!();
     }
 
-    // Interactive repository selection
-    let selector = InteractiveSelector::new();
+    // Interactive repository selection; status is only ever meaningful for
+    // repos already cloned under the current directory, so the picker's
+    // status column stays empty for these not-yet-added repositories - it's
+    // threaded through for consistency with `viewyard view` commands.
+    let selector = InteractiveSelector::new().with_vcs_status(show_status);
     let selected_repos =
         selector.select_repositories_with_existing(available_repos, existing_repos)?;
 
@@ -726,7 +1112,7 @@ fn select_repositories_for_update(
     }
 
     // Confirm selection
-    if !InteractiveSelector::confirm_selection(&selected_repos)? {
+    if !selector.confirm_selection(&selected_repos)? {
         ui::print_info("Repository selection cancelled.");
         return Ok(Vec::new());
     }
@@ -734,7 +1120,13 @@ fn select_repositories_for_update(
     Ok(selected_repos)
 }
 
-fn update_viewset(account: Option<&str>) -> Result<()> {
+fn update_viewset(
+    account: Option<&str>,
+    offline: bool,
+    no_status: bool,
+    discovery_filter: search::DiscoveryFilter,
+    clone_strategy: models::CloneStrategy,
+) -> Result<()> {
     ui::print_info("Updating viewset with new repositories");
 
     // Check if git is available
@@ -742,49 +1134,67 @@ fn update_viewset(account: Option<&str>) -> Result<()> {
 
     // Detect viewset context (must be in viewset root for update)
     let current_dir = std::env::current_dir()?;
-    let repos_file = current_dir.join(".viewyard-repos.json");
-
-    if !repos_file.exists() {
+    let Some((repos_file, format)) = repos_file::find_repos_file(&current_dir) else {
         ui::show_error_with_help(
             "Not in a viewset directory",
             &[
                 &format!("Current directory: {}", current_dir.display()),
-                "Expected: directory containing .viewyard-repos.json",
+                "Expected: directory containing .viewyard-repos.json or .viewyard-repos.toml",
                 "Navigate to a viewset directory first",
                 "Or create a new viewset: viewyard viewset create <name>",
             ],
         );
         return Err(anyhow::anyhow!("Not in a viewset directory"));
-    }
+    };
 
     // Load existing repositories
     let existing_repos = load_and_validate_repos(&repos_file)?;
 
     // Discover available repositories
-    let Ok(all_repos) = discover_repositories_for_viewset(account) else {
+    let Ok(all_repos) = discover_repositories_for_viewset(account, offline) else {
         ui::print_info("Falling back to manual repository management.");
-        ui::print_info("Edit .viewyard-repos.json manually to add repositories.");
+        ui::print_info(&format!("Edit {} manually to add repositories.", format.filename()));
         return Ok(());
     };
 
-    // Filter out repositories that already exist
+    // Filter out repositories that already exist, then narrow by
+    // --topic/--language/--search before they ever reach the picker
     let available_repos = filter_existing_repositories(&all_repos, &existing_repos);
+    let available_repos = discovery_filter.apply(&available_repos);
 
     // Interactive selection of new repositories
-    let selected_repos = select_repositories_for_update(&available_repos, &existing_repos)?;
+    let selected_repos =
+        select_repositories_for_update(&available_repos, &existing_repos, !no_status)?;
 
     if selected_repos.is_empty() {
         ui::print_success("No changes made to viewset.");
         return Ok(());
     }
 
-    // Merge existing and new repositories
+    let mut selected_repos = selected_repos;
+    if !clone_strategy.is_default() {
+        for repo in &mut selected_repos {
+            repo.clone_strategy = Some(clone_strategy);
+        }
+    }
+
+    // Merge existing and new repositories, then canonicalize so repeated
+    // updates produce a stable file instead of reordering/duplicating entries
     let mut updated_repos = existing_repos;
     updated_repos.extend(selected_repos.iter().cloned());
+    let updated_repos = repos_file::canonicalize_repos(updated_repos);
 
     // Update the repository configuration file
-    let repos_json = serde_json::to_string_pretty(&updated_repos)?;
-    std::fs::write(&repos_file, repos_json)?;
+    let serialized = repos_file::serialize_repos(&updated_repos, format)?;
+
+    if git::exec_mode() == git::ExecMode::DryRun {
+        let current = std::fs::read_to_string(&repos_file).unwrap_or_default();
+        ui::print_info(&format!("Would write to {}:", repos_file.display()));
+        print!("{}", repos_file::diff_lines(&current, &serialized));
+        return Ok(());
+    }
+
+    std::fs::write(&repos_file, serialized)?;
 
     ui::print_success(&format!(
         "Viewset updated successfully! Added {} new repositories.",
@@ -804,3 +1214,481 @@ fn update_viewset(account: Option<&str>) -> Result<()> {
 
     Ok(())
 }
+
+/// Entries in `existing_repos` whose name no longer appears in
+/// `remote_repos`, e.g. because they were archived, renamed, or deleted
+/// upstream since the viewset was created.
+fn find_repos_missing_upstream<'a>(
+    existing_repos: &'a [models::Repository],
+    remote_repos: &[models::Repository],
+) -> Vec<&'a models::Repository> {
+    let remote_names: std::collections::HashSet<&str> =
+        remote_repos.iter().map(|r| r.name.as_str()).collect();
+
+    existing_repos
+        .iter()
+        .filter(|repo| !remote_names.contains(repo.name.as_str()))
+        .collect()
+}
+
+/// Interactively remove repositories from a viewset's manifest - the
+/// inverse of [`update_viewset`], which only ever adds. Cross-checks
+/// `existing_repos` against a fresh discovery pass to flag ones that no
+/// longer exist on the remote account, then lets the user multi-select
+/// (from the full list, not just the flagged ones) what to drop, with the
+/// same confirm-before-write step `update_viewset` uses.
+fn prune_viewset(account: Option<&str>, offline: bool) -> Result<()> {
+    ui::print_info("Reviewing viewset repositories for removal");
+
+    let current_dir = std::env::current_dir()?;
+    let Some((repos_file, format)) = repos_file::find_repos_file(&current_dir) else {
+        ui::show_error_with_help(
+            "Not in a viewset directory",
+            &[
+                &format!("Current directory: {}", current_dir.display()),
+                "Expected: directory containing .viewyard-repos.json or .viewyard-repos.toml",
+                "Navigate to a viewset directory first",
+            ],
+        );
+        return Err(anyhow::anyhow!("Not in a viewset directory"));
+    };
+
+    let existing_repos = load_and_validate_repos(&repos_file)?;
+    if existing_repos.is_empty() {
+        ui::print_info("Viewset has no repositories to prune.");
+        return Ok(());
+    }
+
+    match discover_repositories_for_viewset(account, offline) {
+        Ok(remote_repos) => {
+            let stale = find_repos_missing_upstream(&existing_repos, &remote_repos);
+            if stale.is_empty() {
+                ui::print_success("Every repository in the viewset still exists on the remote account.");
+            } else {
+                ui::print_warning("No longer found on the remote account:");
+                for repo in &stale {
+                    ui::print_warning(&format!("  • {}", repo.name));
+                }
+            }
+        }
+        Err(e) => ui::print_info(&format!(
+            "Skipping remote cross-check ({e}); showing all repositories for manual review."
+        )),
+    }
+
+    ui::print_info(&format!("Viewset has {} repositories:", existing_repos.len()));
+    for repo in &existing_repos {
+        ui::print_info(&format!("  • {}", repo.name));
+    }
+    println!();
+
+    let selector = InteractiveSelector::new();
+    ui::print_info("Select repositories to remove:");
+    let to_remove = selector.select_repositories(&existing_repos)?;
+
+    if to_remove.is_empty() {
+        ui::print_success("No changes made to viewset.");
+        return Ok(());
+    }
+
+    if !selector.confirm_selection(&to_remove)? {
+        ui::print_info("Repository removal cancelled.");
+        return Ok(());
+    }
+
+    let remove_names: std::collections::HashSet<&str> =
+        to_remove.iter().map(|r| r.name.as_str()).collect();
+    let updated_repos: Vec<models::Repository> = existing_repos
+        .into_iter()
+        .filter(|repo| !remove_names.contains(repo.name.as_str()))
+        .collect();
+
+    let serialized = repos_file::serialize_repos(&updated_repos, format)?;
+
+    if git::exec_mode() == git::ExecMode::DryRun {
+        let current = std::fs::read_to_string(&repos_file).unwrap_or_default();
+        ui::print_info(&format!("Would write to {}:", repos_file.display()));
+        print!("{}", repos_file::diff_lines(&current, &serialized));
+        return Ok(());
+    }
+
+    std::fs::write(&repos_file, serialized)?;
+
+    ui::print_success(&format!(
+        "Viewset updated successfully! Removed {} repositories.",
+        to_remove.len()
+    ));
+    ui::print_info("Removed repositories:");
+    for repo in &to_remove {
+        ui::print_info(&format!("  • {}", repo.name));
+    }
+
+    ui::print_info("");
+    ui::print_info("Next steps:");
+    ui::print_info("  • Prune matching checkouts from views: viewyard view remove <view-name>, then viewyard view create <view-name> again");
+
+    Ok(())
+}
+
+/// Reconcile the current viewset's `.viewyard-repos.json` against a forge
+/// org/group (`<forge>:<org>`), adding any repositories it has that aren't
+/// already present by name. Existing entries, including any hand-edited
+/// overrides, are left untouched.
+fn sync_viewset(spec: &str) -> Result<()> {
+    ui::print_info(&format!("Syncing viewset from {spec}"));
+
+    let current_dir = std::env::current_dir()?;
+    let Some((repos_file, format)) = repos_file::find_repos_file(&current_dir) else {
+        ui::show_error_with_help(
+            "Not in a viewset directory",
+            &[
+                &format!("Current directory: {}", current_dir.display()),
+                "Expected: directory containing .viewyard-repos.json or .viewyard-repos.toml",
+                "Navigate to a viewset directory first",
+                "Or create a new viewset: viewyard viewset create <name>",
+            ],
+        );
+        return Err(anyhow::anyhow!("Not in a viewset directory"));
+    };
+
+    let existing_repos = load_and_validate_repos(&repos_file)?;
+
+    let target = providers::parse_forge_sync_target(spec)?;
+    if !target.provider.check_availability()? {
+        anyhow::bail!(
+            "{} is not reachable or not authenticated; check its token/URL environment variables",
+            target.provider.name()
+        );
+    }
+
+    ui::print_info(&format!(
+        "Fetching repositories for {}'s '{}' org/group...",
+        target.provider.name(),
+        target.org
+    ));
+    let discovered_repos = target.provider.discover_repositories_for_org(&target.org)?;
+
+    if discovered_repos.is_empty() {
+        ui::print_warning(&format!(
+            "No repositories found for '{}' on {}",
+            target.org,
+            target.provider.name()
+        ));
+        return Ok(());
+    }
+
+    let new_repos = filter_existing_repositories(&discovered_repos, &existing_repos);
+
+    if new_repos.is_empty() {
+        ui::print_success("Viewset already up to date; no new repositories found.");
+        return Ok(());
+    }
+
+    let mut updated_repos = existing_repos;
+    updated_repos.extend(new_repos.iter().cloned());
+
+    let serialized = repos_file::serialize_repos(&updated_repos, format)?;
+
+    if git::exec_mode() == git::ExecMode::DryRun {
+        let current = std::fs::read_to_string(&repos_file).unwrap_or_default();
+        ui::print_info(&format!("Would write to {}:", repos_file.display()));
+        print!("{}", repos_file::diff_lines(&current, &serialized));
+        return Ok(());
+    }
+
+    std::fs::write(&repos_file, serialized)?;
+
+    ui::print_success(&format!(
+        "Viewset synced successfully! Added {} new repositories from {}.",
+        new_repos.len(),
+        target.org
+    ));
+    ui::print_info("Added repositories:");
+    for repo in &new_repos {
+        ui::print_info(&format!("  • {}", repo.name));
+    }
+
+    ui::print_info("");
+    ui::print_info("Next steps:");
+    ui::print_info("  • Update existing views: viewyard view update");
+    ui::print_info("  • Or create a new view: viewyard view create <view-name>");
+
+    Ok(())
+}
+
+/// Per-view drift between `.viewyard-repos.json` and what's actually on disk.
+struct ViewDrift {
+    /// Manifest repos with no directory in this view yet.
+    missing: Vec<models::Repository>,
+    /// Directories in this view that aren't any manifest repo's name.
+    unmanaged: Vec<String>,
+    /// Manifest repos whose checked-out `origin` no longer matches.
+    remote_mismatches: Vec<(String, String, String)>, // (name, expected url, actual url)
+}
+
+impl ViewDrift {
+    fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.unmanaged.is_empty() && self.remote_mismatches.is_empty()
+    }
+}
+
+/// Walk every view directory under the viewset root and report three kinds
+/// of drift against `.viewyard-repos.json`: repos the manifest lists but
+/// the view hasn't cloned yet, directories on disk the manifest doesn't
+/// know about ("unmanaged"), and repos whose checked-out `origin` remote no
+/// longer matches the manifest's URL. With `fix`, missing repos are cloned
+/// into their view (reusing [`clone_and_setup_repository_in_view`]) and
+/// unmanaged repos are appended back into the manifest after reading their
+/// `origin` URL; remote mismatches are only ever reported, since picking a
+/// "correct" side isn't this command's call to make. Unless `no_status` is
+/// set, every repo actually present in the view also gets a starship-style
+/// [`git::status_summary`] line (ahead/behind, diverged, dirty, stash) so
+/// drift in content, not just in existence, is visible at a glance.
+fn reconcile_viewset(fix: bool, no_status: bool) -> Result<()> {
+    let viewset_context = detect_viewset_context()?;
+    let viewset_root = &viewset_context.viewset_root;
+
+    let Some((repos_file_path, format)) = repos_file::find_repos_file(viewset_root) else {
+        ui::show_error_with_help(
+            "Not in a viewset directory",
+            &["Expected: directory containing .viewyard-repos.json or .viewyard-repos.toml"],
+        );
+        return Err(anyhow::anyhow!("Not in a viewset directory"));
+    };
+    let manifest_repos = load_and_validate_repos(&repos_file_path)?;
+
+    let view_dirs = discover_view_dirs(viewset_root)?;
+    if view_dirs.is_empty() {
+        ui::print_info("No views found under this viewset - nothing to reconcile");
+        return Ok(());
+    }
+
+    let mut all_unmanaged: Vec<(String, std::path::PathBuf)> = Vec::new();
+    let mut any_drift = false;
+
+    for (view_name, view_path) in &view_dirs {
+        let drift = diff_view_against_manifest(view_path, &manifest_repos);
+
+        ui::print_header(&format!("View '{view_name}'"));
+        if !no_status {
+            print_view_repo_status(view_path, &manifest_repos);
+        }
+
+        if drift.is_clean() {
+            ui::print_success(&format!("View '{view_name}' matches the manifest"));
+            continue;
+        }
+        any_drift = true;
+
+        if !drift.missing.is_empty() {
+            ui::print_warning(&format!(
+                "Missing from this view: {}",
+                drift.missing.iter().map(|r| r.name.as_str()).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        if !drift.unmanaged.is_empty() {
+            ui::print_warning(&format!("Unmanaged on disk: {}", drift.unmanaged.join(", ")));
+        }
+        for (name, expected, actual) in &drift.remote_mismatches {
+            ui::print_warning(&format!(
+                "Remote drift in '{name}': manifest has '{expected}', checked-out repo has '{actual}'"
+            ));
+        }
+
+        if fix && git::exec_mode() == git::ExecMode::DryRun {
+            for repo in &drift.missing {
+                ui::print_info(&format!("  would clone {} into view '{view_name}'", repo.name));
+            }
+        } else if fix {
+            let worker_count = parallel::resolve_worker_count(drift.missing.len(), None);
+            let viewset_root_for_workers = viewset_root.clone();
+            let view_path_for_workers = view_path.clone();
+            let view_name_for_workers = view_name.clone();
+            let progress = ui::Progress::new(drift.missing.len());
+            let preferred_auth = config::auth_method_for_viewset_root(viewset_root);
+            let results = parallel::parallel_map(drift.missing.clone(), worker_count, move |repo| {
+                let result = clone_and_setup_repository_in_view(
+                    &repo,
+                    &viewset_root_for_workers,
+                    &view_path_for_workers,
+                    &view_name_for_workers,
+                    &progress,
+                    preferred_auth,
+                );
+                (repo.name, result)
+            });
+            for (name, result) in results {
+                match result {
+                    Ok(()) => ui::print_info(&format!("  ✓ Cloned {name}")),
+                    Err(e) => ui::print_error(&format!("  Failed to clone {name}: {e}")),
+                }
+            }
+        }
+
+        for dir_name in &drift.unmanaged {
+            all_unmanaged.push((dir_name.clone(), view_path.join(dir_name)));
+        }
+    }
+
+    if !any_drift {
+        ui::print_success("Every view matches the manifest");
+        return Ok(());
+    }
+
+    if fix && !all_unmanaged.is_empty() {
+        let mut updated_repos = manifest_repos.clone();
+        let mut added = Vec::new();
+
+        for (name, repo_path) in &all_unmanaged {
+            if updated_repos.iter().any(|r| &r.name == name) {
+                continue; // Already folded in from another view this same run
+            }
+            match git::run_git_command_string(&["remote", "get-url", "origin"], Some(repo_path)) {
+                Ok(url) => {
+                    let repo = models::Repository {
+                        name: name.clone(),
+                        url: url.trim().to_string(),
+                        is_private: false,
+                        source: "Unmanaged (discovered by viewset sync)".to_string(),
+                        account: None,
+                        category: None,
+                        backend: None,
+                        topics: Vec::new(),
+                        language: None,
+                        clone_strategy: None,
+                    };
+                    added.push(repo.clone());
+                    updated_repos.push(repo);
+                }
+                Err(e) => ui::print_warning(&format!(
+                    "Could not read origin remote for unmanaged repo '{name}': {e}"
+                )),
+            }
+        }
+
+        if !added.is_empty() {
+            let serialized = repos_file::serialize_repos(&updated_repos, format)?;
+
+            if git::exec_mode() == git::ExecMode::DryRun {
+                let current = std::fs::read_to_string(&repos_file_path).unwrap_or_default();
+                ui::print_info(&format!("Would write to {}:", repos_file_path.display()));
+                print!("{}", repos_file::diff_lines(&current, &serialized));
+                return Ok(());
+            }
+
+            std::fs::write(&repos_file_path, serialized)?;
+            ui::print_success(&format!(
+                "Added {} unmanaged repositor{} back into the manifest: {}",
+                added.len(),
+                if added.len() == 1 { "y" } else { "ies" },
+                added.iter().map(|r| r.name.as_str()).collect::<Vec<_>>().join(", ")
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Directories directly under `viewset_root` that look like views, paired
+/// with their names, sorted for deterministic report order. Skips the
+/// shared mirror store and other dotfiles/dotdirs (`.viewyard-repos.json`,
+/// `.git`, etc).
+fn discover_view_dirs(viewset_root: &std::path::Path) -> Result<Vec<(String, std::path::PathBuf)>> {
+    let mut views = Vec::new();
+
+    for entry in std::fs::read_dir(viewset_root)
+        .with_context(|| format!("Failed to read viewset directory: {}", viewset_root.display()))?
+    {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        if name.starts_with('.') {
+            continue;
+        }
+        views.push((name, entry.path()));
+    }
+
+    views.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(views)
+}
+
+/// Compare one view directory's repo subdirectories against `manifest_repos`.
+fn diff_view_against_manifest(
+    view_path: &std::path::Path,
+    manifest_repos: &[models::Repository],
+) -> ViewDrift {
+    let mut missing = Vec::new();
+    let mut remote_mismatches = Vec::new();
+
+    for repo in manifest_repos {
+        let repo_path = view_path.join(&repo.name);
+        if !repo_path.exists() {
+            missing.push(repo.clone());
+            continue;
+        }
+
+        if vcs::Backend::from_repo(repo) != vcs::Backend::Git {
+            continue;
+        }
+        if let Ok(actual_url) = git::run_git_command_string(&["remote", "get-url", "origin"], Some(&repo_path)) {
+            let actual_url = actual_url.trim();
+            if actual_url != repo.url {
+                remote_mismatches.push((repo.name.clone(), repo.url.clone(), actual_url.to_string()));
+            }
+        }
+    }
+
+    let manifest_names: std::collections::HashSet<&str> =
+        manifest_repos.iter().map(|r| r.name.as_str()).collect();
+    let mut unmanaged: Vec<String> = std::fs::read_dir(view_path)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        // `DirEntry::file_type` doesn't follow symlinks, so a symlinked
+        // directory (a potential loop back into the view) is never treated
+        // as a repo root here.
+        .filter(|entry| entry.file_type().is_ok_and(|ft| ft.is_dir()))
+        .filter(|entry| is_repo_root(&entry.path()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|dir_name| !manifest_names.contains(dir_name.as_str()))
+        .collect();
+    unmanaged.sort();
+
+    ViewDrift {
+        missing,
+        unmanaged,
+        remote_mismatches,
+    }
+}
+
+/// Print a starship-style `⇡N`/`⇣N`/`⇕`/`!`/`+`/`?`/`$` status line (see
+/// [`git::StatusSummary::render`]) for every manifest repo actually cloned
+/// into `view_path`, so drift in a repo's *content* - not just whether it
+/// exists - shows up alongside [`diff_view_against_manifest`]'s report.
+/// Non-git backends and repos that error out (e.g. a detached/unborn HEAD)
+/// are skipped rather than guessed at.
+fn print_view_repo_status(view_path: &std::path::Path, manifest_repos: &[models::Repository]) {
+    for repo in manifest_repos {
+        let repo_path = view_path.join(&repo.name);
+        if vcs::Backend::from_repo(repo) != vcs::Backend::Git || !repo_path.exists() {
+            continue;
+        }
+        if let Ok(summary) = git::status_summary(&repo_path) {
+            ui::print_info(&format!("  {} {}", repo.name, summary.render()));
+        }
+    }
+}
+
+/// Whether `path` is the root of a checkout this tool recognizes, i.e. it
+/// has a `.git` entry (a directory, or the file a worktree has) or a `.hg`
+/// directory directly inside it. Used to tell
+/// unmanaged *repos* apart from incidental scratch directories a view
+/// happens to contain, without descending any further once a repo root is
+/// found.
+fn is_repo_root(path: &std::path::Path) -> bool {
+    path.join(".git").exists() || path.join(".hg").is_dir()
+}
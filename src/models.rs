@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Repository {
     pub name: String,
     pub url: String,
@@ -9,6 +10,102 @@ pub struct Repository {
     pub source: String, // e.g., "GitHub (username)" or "GitHub (org/username)"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub account: Option<String>, // Optional explicit account field for git user configuration
+    /// Optional freeform category (e.g. "backend", "infra"), letting tools
+    /// like [`crate::interactive::InteractiveSelector`] offer category-scoped
+    /// selection instead of hunting through dozens of repos by name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Which version control system this repo uses ("git", "hg"/"mercurial"),
+    /// resolved via [`crate::vcs::Backend::from_repo`]. Unset defaults to
+    /// `git`, matching viewyard's historical git-only behavior.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<String>,
+    /// Repo topics as reported by the forge (e.g. GitHub's `repositoryTopics`),
+    /// letting discovery be narrowed with `--topic` before the interactive
+    /// picker. Empty when the source a repo was discovered from doesn't
+    /// report topics.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<String>,
+    /// Primary language as reported by the forge (e.g. GitHub's
+    /// `primaryLanguage`), for narrowing discovery with `--language`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// How deep/wide `view create`/`view update` should clone this repo.
+    /// Unset clones full history on every branch, matching viewyard's
+    /// historical behavior.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clone_strategy: Option<CloneStrategy>,
+}
+
+impl Repository {
+    /// See [`cache_key_for_url`].
+    #[must_use]
+    pub fn cache_key(&self) -> String {
+        cache_key_for_url(&self.url)
+    }
+}
+
+/// Deterministic, filesystem-safe key derived from a repo's clone URL,
+/// identifying its shared local mirror in [`crate::store`] independent of
+/// display name - so a repo rename upstream doesn't orphan its existing
+/// cache, and two distinct repos that happen to share a name across
+/// different forges/accounts don't collide on the same mirror directory.
+#[must_use]
+pub fn cache_key_for_url(url: &str) -> String {
+    url.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+/// Clone depth/breadth knobs for a single repo, persisted per-repo (rather
+/// than viewset-wide) since a monorepo-sized dependency might want
+/// `--depth 1` while a small sibling repo is cloned in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloneStrategy {
+    /// `git clone --depth N` / `git clone --mirror --depth N`. `None` clones
+    /// full history.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub depth: Option<u32>,
+    /// `git clone --single-branch`, fetching only the view's branch instead
+    /// of every branch the mirror would otherwise track.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub single_branch: bool,
+    /// `git clone --filter=blob:none`, a blobless partial clone that fetches
+    /// commits/trees up front and file contents on demand.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub blobless: bool,
+}
+
+impl CloneStrategy {
+    /// Whether this strategy differs from viewyard's historical default
+    /// (full clone, every branch, every blob) and so is worth persisting.
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self.depth.is_none() && !self.single_branch && !self.blobless
+    }
+
+    /// Extra `git clone`/`git clone --mirror` arguments this strategy adds.
+    /// `branch_name` is only used when `single_branch` is set, since
+    /// `--single-branch` needs a `--branch <name>` to know which one.
+    #[must_use]
+    pub fn clone_args(&self, branch_name: &str) -> Vec<String> {
+        let mut args = Vec::new();
+
+        if let Some(depth) = self.depth {
+            args.push("--depth".to_string());
+            args.push(depth.to_string());
+        }
+        if self.single_branch {
+            args.push("--single-branch".to_string());
+            args.push("--branch".to_string());
+            args.push(branch_name.to_string());
+        }
+        if self.blobless {
+            args.push("--filter=blob:none".to_string());
+        }
+
+        args
+    }
 }
 
 impl fmt::Display for Repository {
@@ -16,3 +113,119 @@ impl fmt::Display for Repository {
         write!(f, "{}", self.name)
     }
 }
+
+/// A named group of repositories checked out together under one workspace
+/// root (`~/src/src-<name>/`), each view being a fresh worktree of that set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct Viewset {
+    pub repos: Vec<Repository>,
+    /// Freeform labels on the viewset itself (e.g. "release-1.2"), for
+    /// grouping viewsets rather than the repos inside one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Freeform labels per repo name (e.g. "backend"), letting `view create
+    /// --tag` and `view list --tag` select/filter by logical subset instead
+    /// of naming every repo.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub repo_tags: HashMap<String, Vec<String>>,
+    /// Per-repo override of the trunk branch triple checked by `view
+    /// validate-branches`, keyed by repo name. Repos absent from this map use
+    /// [`TrunkBranches::default`] (`main`/`next`/`dev`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub trunk_branches: HashMap<String, TrunkBranches>,
+    /// Override for where this viewset's workspace root lives, taking
+    /// priority over [`ViewsetsConfig::root_template`]. May be an absolute
+    /// path or a template containing `{viewset}`, which is substituted with
+    /// this viewset's name. Unset viewsets fall back to the config-level
+    /// template, and ultimately to `~/src/src-{viewset}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_root: Option<String>,
+    /// Pin one auth transport for every clone/fetch in this viewset, rather
+    /// than letting [`crate::credentials::resolve`] probe for an SSH agent
+    /// first - useful behind a corporate firewall that blocks SSH outright,
+    /// where that probe only costs a connect timeout before falling back.
+    #[serde(default, skip_serializing_if = "crate::credentials::AuthMethod::is_default")]
+    pub preferred_auth_method: crate::credentials::AuthMethod,
+}
+
+/// The `main`/`next`/`dev` trunk triple `view validate-branches` enforces an
+/// ancestry order over, with each name overridable independently for repos
+/// that don't follow the convention.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrunkBranches {
+    #[serde(default = "TrunkBranches::default_main")]
+    pub main: String,
+    #[serde(default = "TrunkBranches::default_next")]
+    pub next: String,
+    #[serde(default = "TrunkBranches::default_dev")]
+    pub dev: String,
+}
+
+impl TrunkBranches {
+    fn default_main() -> String {
+        "main".to_string()
+    }
+
+    fn default_next() -> String {
+        "next".to_string()
+    }
+
+    fn default_dev() -> String {
+        "dev".to_string()
+    }
+}
+
+impl Default for TrunkBranches {
+    fn default() -> Self {
+        Self {
+            main: Self::default_main(),
+            next: Self::default_next(),
+            dev: Self::default_dev(),
+        }
+    }
+}
+
+/// Top-level shape of `~/.config/viewyard/viewsets.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ViewsetsConfig {
+    pub viewsets: HashMap<String, Viewset>,
+    /// Default template for every viewset's workspace root, containing
+    /// `{viewset}` as a placeholder for the viewset name (e.g.
+    /// `~/work/{viewset}`). Individual viewsets may override this via
+    /// [`Viewset::workspace_root`]. Falls back to `~/src/src-{viewset}` when
+    /// unset, matching viewyard's historical hardcoded layout.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root_template: Option<String>,
+}
+
+impl ViewsetsConfig {
+    /// The alphabetically-first viewset name, used as a stable default when
+    /// the caller hasn't specified or auto-detected one.
+    #[must_use]
+    pub fn get_first_viewset_name(&self) -> Option<String> {
+        self.viewsets.keys().min().cloned()
+    }
+}
+
+/// One repo's status line, in structured form - the machine-readable
+/// counterpart to the prose `get_repo_status` builds for `--format text`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepoStatus {
+    pub name: String,
+    pub branch: String,
+    pub ahead: usize,
+    pub behind: usize,
+    pub dirty: bool,
+    pub untracked: bool,
+    pub conflicted: bool,
+    pub stashed: bool,
+}
+
+/// `viewyard status`'s full result for `--format json`: the view it ran
+/// against plus every selected repo's [`RepoStatus`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ViewStatus {
+    pub viewset: String,
+    pub view: String,
+    pub repos: Vec<RepoStatus>,
+}
@@ -0,0 +1,234 @@
+//! Workspace-wide undo via a per-view operation snapshot log.
+//!
+//! Mirrors GitButler's snapshot/oplog: before a destructive workspace
+//! command (`rebase`, `commit-all`, `push-all`) touches a repo, record its
+//! current branch, HEAD SHA, and stash count as a [`SnapshotDetails`] entry
+//! in `.viewyard-oplog.json` at the view root. `viewyard undo` then reads
+//! the most recent entry and restores whichever repos have moved since.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::git;
+use crate::ui;
+
+/// Bounded ring of the most recent oplog entries, so `.viewyard-oplog.json`
+/// doesn't grow unbounded across a view's lifetime.
+const MAX_OPLOG_ENTRIES: usize = 20;
+
+/// Which workspace command produced a [`SnapshotDetails`] entry, recorded so
+/// `viewyard undo` can describe what it's reverting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OperationKind {
+    Rebase,
+    CommitAll,
+    PushAll,
+}
+
+impl std::fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Rebase => "rebase",
+            Self::CommitAll => "commit-all",
+            Self::PushAll => "push-all",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A single repo's state captured just before an operation ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoSnapshot {
+    pub repo_name: String,
+    pub branch: String,
+    pub head_sha: String,
+    pub stash_count: usize,
+}
+
+/// One oplog entry: an operation and the pre-state of every repo it was
+/// about to touch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotDetails {
+    pub operation: OperationKind,
+    pub timestamp_secs: u64,
+    pub repos: Vec<RepoSnapshot>,
+}
+
+fn oplog_path(view_root: &Path) -> PathBuf {
+    view_root.join(".viewyard-oplog.json")
+}
+
+fn load_oplog(view_root: &Path) -> Result<Vec<SnapshotDetails>> {
+    let path = oplog_path(view_root);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read oplog: {}", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("Failed to parse oplog: {}", path.display()))
+}
+
+fn save_oplog(view_root: &Path, entries: &[SnapshotDetails]) -> Result<()> {
+    let path = oplog_path(view_root);
+    let content = serde_json::to_string_pretty(entries).context("Failed to serialize oplog")?;
+    std::fs::write(&path, content).with_context(|| format!("Failed to write oplog: {}", path.display()))
+}
+
+fn current_timestamp_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Capture a [`RepoSnapshot`] for `repo_path`, tolerating a repo whose state
+/// can't be read (e.g. mid-failure already) by recording empty/zeroed fields
+/// rather than failing the whole snapshot.
+fn capture_repo_snapshot(repo_name: &str, repo_path: &Path) -> RepoSnapshot {
+    RepoSnapshot {
+        repo_name: repo_name.to_string(),
+        branch: git::get_current_branch(repo_path).unwrap_or_default(),
+        head_sha: git::run_git_command_string(&["rev-parse", "HEAD"], Some(repo_path)).unwrap_or_default(),
+        stash_count: git::get_stash_count(repo_path).unwrap_or(0),
+    }
+}
+
+/// Snapshot every `(repo_name, repo_path)` pair and append the entry to
+/// `.viewyard-oplog.json` under `view_root`, dropping the oldest entries so
+/// only the newest [`MAX_OPLOG_ENTRIES`] are kept.
+pub fn record_snapshot(view_root: &Path, operation: OperationKind, repos: &[(String, PathBuf)]) -> Result<()> {
+    let repo_snapshots = repos
+        .iter()
+        .map(|(name, path)| capture_repo_snapshot(name, path))
+        .collect();
+
+    let entry = SnapshotDetails {
+        operation,
+        timestamp_secs: current_timestamp_secs(),
+        repos: repo_snapshots,
+    };
+
+    let mut entries = load_oplog(view_root)?;
+    entries.push(entry);
+    if entries.len() > MAX_OPLOG_ENTRIES {
+        let excess = entries.len() - MAX_OPLOG_ENTRIES;
+        entries.drain(0..excess);
+    }
+    save_oplog(view_root, &entries)
+}
+
+/// True if the commit at `sha` in the repo at `repo_path` is already
+/// reachable from its upstream tracking ref - i.e. inverted
+/// `has_unpushed_commits`: instead of asking "is HEAD ahead of upstream", ask
+/// "is this specific commit already on the remote". Used to refuse undoing
+/// past commits that have already been shared.
+fn commit_already_pushed(repo_path: &Path, sha: &str) -> bool {
+    if sha.is_empty() {
+        return false;
+    }
+    matches!(
+        git::run_git_command(&["merge-base", "--is-ancestor", sha, "@{u}"], Some(repo_path)),
+        Ok(output) if output.status.success()
+    )
+}
+
+/// Restore the most recent oplog entry's pre-state into each repo in
+/// `repos` (a `repo_name -> repo_path` map for every repo currently in the
+/// view). For each repo whose HEAD no longer matches the recorded SHA:
+/// refuses repos whose current commit has already been pushed (so undo
+/// can't discard shared history), skips repos with uncommitted changes
+/// unless `force`, then `git reset --hard` back to the recorded SHA and pops
+/// any stashes pushed since the snapshot until the stash count is back to the
+/// recorded one. Stashes dropped since the snapshot are not re-created: the
+/// snapshot records only the stash count. The entry is
+/// popped from the log once processed, whether or not every repo in it was
+/// actually restored.
+pub fn undo_last_operation(view_root: &Path, repos: &HashMap<String, PathBuf>, force: bool) -> Result<()> {
+    let mut entries = load_oplog(view_root)?;
+    let Some(entry) = entries.pop() else {
+        anyhow::bail!("No recorded operations to undo in {}", view_root.display());
+    };
+
+    ui::print_header(&format!("Undoing {}", entry.operation));
+
+    let mut restored = Vec::new();
+    let mut skipped = Vec::new();
+
+    for snapshot in &entry.repos {
+        let Some(repo_path) = repos.get(&snapshot.repo_name) else {
+            ui::print_warning(&format!("{}: no longer in this view, skipping", snapshot.repo_name));
+            continue;
+        };
+
+        let current_sha =
+            git::run_git_command_string(&["rev-parse", "HEAD"], Some(repo_path)).unwrap_or_default();
+        if current_sha == snapshot.head_sha {
+            continue; // Nothing changed since the snapshot was taken.
+        }
+
+        if !force && git::has_uncommitted_changes(repo_path).unwrap_or(false) {
+            ui::print_warning(&format!(
+                "{}: has uncommitted changes, skipping (use --force to override)",
+                snapshot.repo_name
+            ));
+            skipped.push(snapshot.repo_name.clone());
+            continue;
+        }
+
+        if commit_already_pushed(repo_path, &current_sha) {
+            ui::print_warning(&format!(
+                "{}: commits since the snapshot have already been pushed, refusing to discard them",
+                snapshot.repo_name
+            ));
+            skipped.push(snapshot.repo_name.clone());
+            continue;
+        }
+
+        match git::run_git_command(&["reset", "--hard", &snapshot.head_sha], Some(repo_path)) {
+            Ok(output) if output.status.success() => {
+                restore_dropped_stashes(repo_path, snapshot.stash_count);
+                let short_sha = &snapshot.head_sha[..snapshot.head_sha.len().min(8)];
+                ui::print_success(&format!("{}: restored to {short_sha}", snapshot.repo_name));
+                restored.push(snapshot.repo_name.clone());
+            }
+            _ => {
+                ui::print_error(&format!("{}: failed to reset to recorded commit", snapshot.repo_name));
+                skipped.push(snapshot.repo_name.clone());
+            }
+        }
+    }
+
+    save_oplog(view_root, &entries)?;
+
+    if !restored.is_empty() {
+        ui::print_success(&format!(
+            "Restored {} repositories: {}",
+            restored.len(),
+            restored.join(", ")
+        ));
+    }
+    if !skipped.is_empty() {
+        ui::print_warning(&format!("Skipped {} repositories: {}", skipped.len(), skipped.join(", ")));
+    }
+
+    Ok(())
+}
+
+/// Pop stashes pushed since the snapshot (most recent first) until the stash
+/// count is back down to `recorded_stash_count`, stopping early if a pop
+/// fails. A count already at or below the recorded one is left as it is, so
+/// stashes dropped since the snapshot are not re-created.
+fn restore_dropped_stashes(repo_path: &Path, recorded_stash_count: usize) {
+    loop {
+        let current = git::get_stash_count(repo_path).unwrap_or(recorded_stash_count);
+        if current <= recorded_stash_count {
+            break;
+        }
+        if git::run_git_command(&["stash", "pop"], Some(repo_path)).is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! A small bounded-concurrency work queue for per-repo git operations.
+//!
+//! Workspace commands (`status`, `rebase`, `commit-all`, `push-all`) run the
+//! same operation once per repo in a view; with no dependency between repos,
+//! there's no reason to pay for that sequentially. [`parallel_map`] runs a
+//! worker pool of plain OS threads (no async runtime dependency is vendored
+//! here) that pull work off a shared queue, while preserving each item's
+//! original index so the caller can print results in view order regardless
+//! of which thread finished first.
+
+use std::sync::Mutex;
+
+/// Apply `f` to every item in `items` using up to `workers` threads at once,
+/// returning results in the same order as `items` (not completion order).
+/// `workers` is clamped to at least 1 and at most `items.len()`.
+pub fn parallel_map<T, R>(items: Vec<T>, workers: usize, f: impl Fn(T) -> R + Sync) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+{
+    let worker_count = workers.clamp(1, items.len().max(1));
+    let queue: Mutex<std::vec::IntoIter<(usize, T)>> =
+        Mutex::new(items.into_iter().enumerate().collect::<Vec<_>>().into_iter());
+    let results: Mutex<Vec<(usize, R)>> = Mutex::new(Vec::new());
+
+    std::thread::scope(|scope| {
+        for _ in 0..worker_count {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Worker count for a batch of `repo_count` repos: one worker per repo up to
+/// the machine's available parallelism, so small views don't pay
+/// thread-spawn overhead for nothing and large ones don't spawn one OS
+/// thread per repo or oversubscribe the CPU.
+#[must_use]
+pub fn default_worker_count(repo_count: usize) -> usize {
+    let available = std::thread::available_parallelism().map_or(4, std::num::NonZeroUsize::get);
+    repo_count.clamp(1, available)
+}
+
+/// Worker count for a batch of `repo_count` repos, honoring an explicit
+/// `--jobs` override when given and falling back to
+/// [`default_worker_count`] otherwise.
+#[must_use]
+pub fn resolve_worker_count(repo_count: usize, jobs: Option<usize>) -> usize {
+    match jobs {
+        None | Some(0) => default_worker_count(repo_count),
+        Some(n) => n.clamp(1, repo_count.max(1)),
+    }
+}
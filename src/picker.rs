@@ -0,0 +1,376 @@
+//! Full-screen incremental fuzzy picker used by `view create`'s interactive
+//! repository selection. Falls back to [`crate::ui::select_from_list`] when
+//! stdin/stdout aren't a real terminal (piped input, the non-interactive
+//! `test-` shortcut), since raw mode and cursor control need one.
+
+use crate::models::Repository;
+use crate::search::RepositorySearch;
+use anyhow::{Context, Result};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crossterm::{cursor, execute, queue, style, terminal};
+use std::collections::{BTreeMap, HashSet};
+use std::io::{self, Write};
+
+/// True when both stdin and stdout are attached to a real terminal. The
+/// picker needs raw mode and cursor control, neither of which make sense for
+/// piped/non-TTY invocations.
+#[must_use]
+pub fn is_interactive_terminal() -> bool {
+    use std::io::IsTerminal;
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+/// Restores the terminal to cooked mode and shows the cursor again even if
+/// the picker returns early via `?` (a render error, a failed event read).
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        enable_raw_mode().context("Failed to enable terminal raw mode")?;
+        execute!(io::stdout(), cursor::Hide).context("Failed to hide cursor")?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show);
+        let _ = disable_raw_mode();
+    }
+}
+
+enum Line {
+    /// A group header: source label and repo count.
+    Header(String, usize),
+    /// A selectable row: index into the flattened match list.
+    Item(usize),
+}
+
+/// Run the picker over `repositories` and return the ones the caller
+/// selected, in their original order. Returns an error if the user cancels
+/// (Esc / Ctrl-C) so `view create` can treat it the same as an empty pick.
+pub fn pick_repositories(repositories: &[Repository]) -> Result<Vec<&Repository>> {
+    if repositories.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let search = RepositorySearch::new();
+    let mut query = String::new();
+    let mut cursor_pos = 0usize;
+    let mut selected: HashSet<String> = HashSet::new();
+
+    let _terminal = TerminalGuard::enter()?;
+    let mut stdout = io::stdout();
+
+    let confirmed = loop {
+        let groups = search.search_grouped(repositories, &query);
+        let flat = flatten(&groups);
+        if cursor_pos >= flat.len() && !flat.is_empty() {
+            cursor_pos = flat.len() - 1;
+        }
+
+        render(&mut stdout, &groups, &flat, &query, &selected, cursor_pos)?;
+
+        match event::read().context("Failed to read terminal input")? {
+            Event::Key(KeyEvent {
+                code, modifiers, ..
+            }) => match code {
+                KeyCode::Esc => break false,
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break false,
+                KeyCode::Enter => break true,
+                KeyCode::Char(' ') => {
+                    if let Some((repo, _, _)) = flat.get(cursor_pos) {
+                        if !selected.remove(&repo.name) {
+                            selected.insert(repo.name.clone());
+                        }
+                    }
+                }
+                KeyCode::Down => cursor_pos = (cursor_pos + 1).min(flat.len().saturating_sub(1)),
+                KeyCode::Char('n') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    cursor_pos = (cursor_pos + 1).min(flat.len().saturating_sub(1));
+                }
+                KeyCode::Up => cursor_pos = cursor_pos.saturating_sub(1),
+                KeyCode::Char('p') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    cursor_pos = cursor_pos.saturating_sub(1);
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                    cursor_pos = 0;
+                }
+                KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                    query.push(c);
+                    cursor_pos = 0;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    };
+
+    if !confirmed {
+        anyhow::bail!("Selection cancelled");
+    }
+
+    if selected.is_empty() {
+        // Nothing was toggled with space - enter picks the highlighted row,
+        // mirroring fzf's single-selection behavior.
+        let groups = search.search_grouped(repositories, &query);
+        if let Some((repo, _, _)) = flatten(&groups).into_iter().nth(cursor_pos) {
+            selected.insert(repo.name.clone());
+        }
+    }
+
+    Ok(repositories
+        .iter()
+        .filter(|r| selected.contains(&r.name))
+        .collect())
+}
+
+/// Run a flat (ungrouped) variant of [`pick_repositories`] for
+/// [`crate::interactive::InteractiveSelector::select_repositories`]: a single
+/// input box that fuzzy-filters via [`RepositorySearch::search`] on every
+/// keystroke, a scrollable cursor-highlighted list, space to toggle, enter
+/// to confirm, and a footer with the running selected count.
+/// `prefilled_query` seeds the input box (e.g. from a prior line-based
+/// search) but is cleared by the first typed character rather than being
+/// typed into, so the user can start a fresh search immediately; a
+/// backspace before that edits the prefilled text instead.
+pub fn pick_repositories_flat<'a>(
+    repositories: &'a [Repository],
+    prefilled_query: &str,
+) -> Result<Vec<&'a Repository>> {
+    if repositories.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let search = RepositorySearch::new();
+    let mut query = prefilled_query.to_string();
+    let mut query_is_prefilled = !prefilled_query.is_empty();
+    let mut cursor_pos = 0usize;
+    let mut selected: HashSet<String> = HashSet::new();
+
+    let _terminal = TerminalGuard::enter()?;
+    let mut stdout = io::stdout();
+
+    let confirmed = loop {
+        let matches = search.search(repositories, &query);
+        if cursor_pos >= matches.len() && !matches.is_empty() {
+            cursor_pos = matches.len() - 1;
+        }
+
+        render_flat(
+            &mut stdout,
+            &matches,
+            repositories.len(),
+            &query,
+            &selected,
+            cursor_pos,
+        )?;
+
+        match event::read().context("Failed to read terminal input")? {
+            Event::Key(KeyEvent {
+                code, modifiers, ..
+            }) => match code {
+                KeyCode::Esc => break false,
+                KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => break false,
+                KeyCode::Enter => break true,
+                KeyCode::Char(' ') => {
+                    if let Some((repo, _)) = matches.get(cursor_pos) {
+                        if !selected.remove(&repo.name) {
+                            selected.insert(repo.name.clone());
+                        }
+                    }
+                }
+                KeyCode::Down => cursor_pos = (cursor_pos + 1).min(matches.len().saturating_sub(1)),
+                KeyCode::Char('n') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    cursor_pos = (cursor_pos + 1).min(matches.len().saturating_sub(1));
+                }
+                KeyCode::Up => cursor_pos = cursor_pos.saturating_sub(1),
+                KeyCode::Char('p') if modifiers.contains(KeyModifiers::CONTROL) => {
+                    cursor_pos = cursor_pos.saturating_sub(1);
+                }
+                KeyCode::Backspace => {
+                    query.pop();
+                    query_is_prefilled = false;
+                    cursor_pos = 0;
+                }
+                KeyCode::Char(c) if !modifiers.contains(KeyModifiers::CONTROL) => {
+                    if query_is_prefilled {
+                        query.clear();
+                        query_is_prefilled = false;
+                    }
+                    query.push(c);
+                    cursor_pos = 0;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    };
+
+    if !confirmed {
+        anyhow::bail!("Selection cancelled");
+    }
+
+    if selected.is_empty() {
+        // Nothing was toggled with space - enter picks the highlighted row,
+        // mirroring fzf's single-selection behavior.
+        let matches = search.search(repositories, &query);
+        if let Some((repo, _)) = matches.get(cursor_pos) {
+            selected.insert(repo.name.clone());
+        }
+    }
+
+    Ok(repositories
+        .iter()
+        .filter(|r| selected.contains(&r.name))
+        .collect())
+}
+
+fn render_flat(
+    stdout: &mut io::Stdout,
+    matches: &[(Repository, i64)],
+    total_repos: usize,
+    query: &str,
+    selected: &HashSet<String>,
+    cursor_pos: usize,
+) -> Result<()> {
+    let (_, term_height) = terminal::size().unwrap_or((80, 24));
+    let fixed_rows = 3; // header + search line + footer
+    let visible_rows = (term_height as usize).saturating_sub(fixed_rows).max(3);
+
+    let scroll_offset = cursor_pos.saturating_sub(visible_rows.saturating_sub(1));
+
+    queue!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    queue!(
+        stdout,
+        style::Print("Select repositories (space: toggle, enter: confirm, esc: cancel)\r\n")
+    )?;
+    queue!(stdout, style::Print(format!("> {query}\r\n")))?;
+
+    for (i, (repo, _score)) in matches.iter().enumerate().skip(scroll_offset).take(visible_rows) {
+        let marker = if selected.contains(&repo.name) {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        let pointer = if i == cursor_pos { ">" } else { " " };
+        queue!(
+            stdout,
+            style::Print(format!("{pointer} {marker} {}\r\n", repo.name))
+        )?;
+    }
+
+    queue!(
+        stdout,
+        style::Print(format!(
+            "\r\n{} selected, showing {} of {}",
+            selected.len(),
+            matches.len(),
+            total_repos
+        ))
+    )?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Flatten the grouped, score-sorted matches into a single navigable list,
+/// in the same order they're rendered.
+fn flatten(
+    groups: &BTreeMap<String, Vec<(Repository, i64, Vec<usize>)>>,
+) -> Vec<(Repository, i64, Vec<usize>)> {
+    groups.values().flatten().cloned().collect()
+}
+
+fn render(
+    stdout: &mut io::Stdout,
+    groups: &BTreeMap<String, Vec<(Repository, i64, Vec<usize>)>>,
+    flat: &[(Repository, i64, Vec<usize>)],
+    query: &str,
+    selected: &HashSet<String>,
+    cursor_pos: usize,
+) -> Result<()> {
+    let (_, term_height) = terminal::size().unwrap_or((80, 24));
+    let fixed_rows = 3; // header + search line + footer
+    let visible_rows = (term_height as usize).saturating_sub(fixed_rows).max(3);
+
+    let mut lines = Vec::new();
+    let mut next_index = 0usize;
+    for (group, matches) in groups {
+        lines.push(Line::Header(group.clone(), matches.len()));
+        for _ in matches {
+            lines.push(Line::Item(next_index));
+            next_index += 1;
+        }
+    }
+
+    let cursor_line = lines
+        .iter()
+        .position(|l| matches!(l, Line::Item(i) if *i == cursor_pos))
+        .unwrap_or(0);
+    let scroll_offset = cursor_line.saturating_sub(visible_rows.saturating_sub(1));
+
+    queue!(
+        stdout,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    queue!(
+        stdout,
+        style::Print("Select repositories (space: toggle, enter: confirm, esc: cancel)\r\n")
+    )?;
+    queue!(stdout, style::Print(format!("> {query}\r\n")))?;
+
+    for line in lines.iter().skip(scroll_offset).take(visible_rows) {
+        match line {
+            Line::Header(name, count) => {
+                queue!(stdout, style::Print(format!("  {name} ({count})\r\n")))?;
+            }
+            Line::Item(i) => {
+                let (repo, _, indices) = &flat[*i];
+                let marker = if selected.contains(&repo.name) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                let pointer = if *i == cursor_pos { ">" } else { " " };
+                queue!(stdout, style::Print(format!("{pointer} {marker} ")))?;
+                render_highlighted_name(stdout, &repo.name, indices)?;
+                queue!(stdout, style::Print("\r\n"))?;
+            }
+        }
+    }
+
+    queue!(
+        stdout,
+        style::Print(format!("\r\n{} selected", selected.len()))
+    )?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Print `name` with the fuzzy-matched characters (at `indices`) highlighted.
+fn render_highlighted_name(
+    stdout: &mut io::Stdout,
+    name: &str,
+    indices: &[usize],
+) -> Result<()> {
+    for (i, ch) in name.chars().enumerate() {
+        if indices.contains(&i) {
+            queue!(
+                stdout,
+                style::SetForegroundColor(style::Color::Yellow),
+                style::Print(ch),
+                style::SetForegroundColor(style::Color::Reset)
+            )?;
+        } else {
+            queue!(stdout, style::Print(ch))?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,419 @@
+//! Forge-agnostic repository discovery.
+//!
+//! [`GitHubService`] started out as the only way to discover repositories.
+//! [`RepositoryProvider`] extracts that shape into a trait so self-hosted
+//! GitLab and Gitea/Forgejo instances can be mirrored the same way.
+
+use anyhow::{Context, Result};
+
+use crate::github::GitHubService;
+use crate::models::Repository;
+
+/// A source of repositories to discover (GitHub, GitLab, Gitea, ...).
+pub trait RepositoryProvider {
+    /// Human-readable name used in log output (e.g. `"GitHub"`).
+    fn name(&self) -> &'static str;
+    /// Whether this provider's CLI/credentials are usable right now.
+    fn check_availability(&self) -> Result<bool>;
+    /// Accounts/users this provider is currently authenticated as.
+    fn get_available_accounts(&self) -> Result<Vec<String>>;
+    /// Discover repositories owned by or accessible to `account`.
+    fn discover_repositories_from_account(&self, account: &str) -> Result<Vec<Repository>>;
+    /// Discover repositories across every available account.
+    fn discover_all_repositories(&self) -> Result<Vec<Repository>> {
+        let accounts = self.get_available_accounts()?;
+        if accounts.is_empty() {
+            anyhow::bail!("No {} accounts found", self.name());
+        }
+
+        let mut all_repos = Vec::new();
+        for account in &accounts {
+            match self.discover_repositories_from_account(account) {
+                Ok(repos) => all_repos.extend(repos),
+                Err(e) => eprintln!(
+                    "Warning: Failed to discover repositories from {} account '{account}': {e}",
+                    self.name()
+                ),
+            }
+        }
+        Ok(all_repos)
+    }
+    /// Discover every repository belonging to a specific org/group by name,
+    /// regardless of which account (if any) is authenticated — e.g. a
+    /// public org the token isn't a member of. Used by `viewyard viewset
+    /// sync <forge>:<org>` to mirror a forge org into `.viewyard-repos.json`.
+    fn discover_repositories_for_org(&self, org: &str) -> Result<Vec<Repository>>;
+}
+
+/// Adapts the existing `GitHubService` associated functions to [`RepositoryProvider`].
+pub struct GitHubProvider;
+
+impl RepositoryProvider for GitHubProvider {
+    fn name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn check_availability(&self) -> Result<bool> {
+        GitHubService::check_availability()
+    }
+
+    fn get_available_accounts(&self) -> Result<Vec<String>> {
+        GitHubService::get_available_accounts()
+    }
+
+    fn discover_repositories_from_account(&self, account: &str) -> Result<Vec<Repository>> {
+        GitHubService::discover_repositories_from_account(account)
+    }
+
+    fn discover_all_repositories(&self) -> Result<Vec<Repository>> {
+        GitHubService::discover_all_repositories()
+    }
+
+    fn discover_repositories_for_org(&self, org: &str) -> Result<Vec<Repository>> {
+        crate::github::discover_org_repositories(org)
+    }
+}
+
+/// Discovers repositories from a self-hosted or gitlab.com GitLab instance
+/// via `GET /api/v4/projects?membership=true`.
+pub struct GitLabProvider {
+    pub base_url: String,
+    pub token: String,
+}
+
+impl RepositoryProvider for GitLabProvider {
+    fn name(&self) -> &'static str {
+        "GitLab"
+    }
+
+    fn check_availability(&self) -> Result<bool> {
+        Ok(!self.token.is_empty())
+    }
+
+    fn get_available_accounts(&self) -> Result<Vec<String>> {
+        let response = ureq::get(&format!("{}/api/v4/user", self.base_url))
+            .set("PRIVATE-TOKEN", &self.token)
+            .call()
+            .context("Failed to fetch GitLab current user")?;
+        let value: serde_json::Value = response.into_json().context("Failed to parse GitLab user response")?;
+        let username = value["username"]
+            .as_str()
+            .context("GitLab user response missing 'username'")?;
+        Ok(vec![username.to_string()])
+    }
+
+    fn discover_repositories_from_account(&self, account: &str) -> Result<Vec<Repository>> {
+        let response = ureq::get(&format!(
+            "{}/api/v4/projects?membership=true&per_page=100",
+            self.base_url
+        ))
+        .set("PRIVATE-TOKEN", &self.token)
+        .call()
+        .context("Failed to list GitLab projects")?;
+
+        let projects: Vec<serde_json::Value> =
+            response.into_json().context("Failed to parse GitLab projects response")?;
+
+        Ok(projects
+            .iter()
+            .filter_map(|p| {
+                let name = p["path"].as_str()?;
+                let url = p["ssh_url_to_repo"].as_str()?;
+                let is_private = p["visibility"].as_str() == Some("private");
+                let topics = gitlab_topics(p);
+                Some(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("GitLab ({account})"),
+                    account: Some(account.to_string()),
+                    category: None,
+                    backend: None,
+                    topics,
+                    // GitLab's project-list endpoint doesn't return the
+                    // primary language; fetching it costs a request per
+                    // project, so it's left unset here.
+                    language: None,
+                    clone_strategy: None,
+                })
+            })
+            .collect())
+    }
+
+    fn discover_repositories_for_org(&self, org: &str) -> Result<Vec<Repository>> {
+        // GitLab groups (including subgroups, whose path contains `/`) are
+        // addressed in the API by URL-encoding the whole path.
+        let encoded_group = org.replace('/', "%2F");
+        let mut repos = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let response = ureq::get(&format!(
+                "{}/api/v4/groups/{encoded_group}/projects?per_page=100&page={page}",
+                self.base_url
+            ))
+            .set("PRIVATE-TOKEN", &self.token)
+            .call()
+            .with_context(|| format!("Failed to list projects for GitLab group '{org}'"))?;
+
+            let projects: Vec<serde_json::Value> =
+                response.into_json().context("Failed to parse GitLab projects response")?;
+            let fetched = projects.len();
+
+            for p in &projects {
+                let (Some(name), Some(url)) = (p["path"].as_str(), p["ssh_url_to_repo"].as_str()) else {
+                    continue;
+                };
+                let is_private = p["visibility"].as_str() == Some("private");
+                repos.push(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("GitLab ({org})"),
+                    account: None,
+                    category: None,
+                    backend: None,
+                    topics: gitlab_topics(p),
+                    language: None,
+                    clone_strategy: None,
+                });
+            }
+
+            if fetched < 100 {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(repos)
+    }
+}
+
+/// Reads a GitLab project's `topics` array (its modern replacement for the
+/// deprecated `tag_list` field).
+fn gitlab_topics(project: &serde_json::Value) -> Vec<String> {
+    project["topics"]
+        .as_array()
+        .map(|topics| topics.iter().filter_map(|t| t.as_str().map(str::to_string)).collect())
+        .unwrap_or_default()
+}
+
+/// Discovers repositories from a Gitea/Forgejo instance via
+/// `GET /api/v1/user/repos`.
+pub struct GiteaProvider {
+    pub base_url: String,
+    pub token: String,
+}
+
+impl RepositoryProvider for GiteaProvider {
+    fn name(&self) -> &'static str {
+        "Gitea"
+    }
+
+    fn check_availability(&self) -> Result<bool> {
+        Ok(!self.token.is_empty())
+    }
+
+    fn get_available_accounts(&self) -> Result<Vec<String>> {
+        let response = ureq::get(&format!("{}/api/v1/user", self.base_url))
+            .set("Authorization", &format!("token {}", self.token))
+            .call()
+            .context("Failed to fetch Gitea current user")?;
+        let value: serde_json::Value = response.into_json().context("Failed to parse Gitea user response")?;
+        let username = value["login"]
+            .as_str()
+            .context("Gitea user response missing 'login'")?;
+        Ok(vec![username.to_string()])
+    }
+
+    fn discover_repositories_from_account(&self, account: &str) -> Result<Vec<Repository>> {
+        let response = ureq::get(&format!("{}/api/v1/user/repos", self.base_url))
+            .set("Authorization", &format!("token {}", self.token))
+            .call()
+            .context("Failed to list Gitea repositories")?;
+
+        let repos: Vec<serde_json::Value> =
+            response.into_json().context("Failed to parse Gitea repositories response")?;
+
+        Ok(repos
+            .iter()
+            .filter_map(|r| {
+                let name = r["name"].as_str()?;
+                let url = r["ssh_url"].as_str()?;
+                let is_private = r["private"].as_bool().unwrap_or(false);
+                Some(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("Gitea ({account})"),
+                    account: Some(account.to_string()),
+                    category: None,
+                    backend: None,
+                    // Gitea's repo-list endpoint doesn't include topics; that
+                    // needs the separate `/repos/{owner}/{repo}/topics` call.
+                    topics: Vec::new(),
+                    language: gitea_language(r),
+                    clone_strategy: None,
+                })
+            })
+            .collect())
+    }
+
+    fn discover_repositories_for_org(&self, org: &str) -> Result<Vec<Repository>> {
+        let mut repos = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let response = ureq::get(&format!("{}/api/v1/orgs/{org}/repos", self.base_url))
+                .query("limit", "50")
+                .query("page", &page.to_string())
+                .set("Authorization", &format!("token {}", self.token))
+                .call()
+                .with_context(|| format!("Failed to list repositories for Gitea org '{org}'"))?;
+
+            let page_repos: Vec<serde_json::Value> =
+                response.into_json().context("Failed to parse Gitea org repositories response")?;
+            let fetched = page_repos.len();
+
+            for r in &page_repos {
+                let (Some(name), Some(url)) = (r["name"].as_str(), r["ssh_url"].as_str()) else {
+                    continue;
+                };
+                let is_private = r["private"].as_bool().unwrap_or(false);
+                repos.push(Repository {
+                    name: name.to_string(),
+                    url: url.to_string(),
+                    is_private,
+                    source: format!("Gitea ({org})"),
+                    account: None,
+                    category: None,
+                    backend: None,
+                    topics: Vec::new(),
+                    language: gitea_language(r),
+                    clone_strategy: None,
+                });
+            }
+
+            if fetched < 50 {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(repos)
+    }
+}
+
+/// Reads a Gitea repo's `language` field, treating the empty string Gitea
+/// uses for "unknown" the same as it being absent.
+fn gitea_language(repo: &serde_json::Value) -> Option<String> {
+    repo["language"].as_str().filter(|lang| !lang.is_empty()).map(str::to_string)
+}
+
+/// Discover repositories across every configured provider (GitHub plus any
+/// self-hosted forges), merging results into a single list.
+pub fn discover_all_repositories(providers: &[Box<dyn RepositoryProvider>]) -> Result<Vec<Repository>> {
+    let mut all_repos = Vec::new();
+    for provider in providers {
+        match provider.discover_all_repositories() {
+            Ok(repos) => all_repos.extend(repos),
+            Err(e) => eprintln!("Warning: {} discovery failed: {e}", provider.name()),
+        }
+    }
+    Ok(all_repos)
+}
+
+/// A `<forge>:<org>` sync target resolved to a concrete provider, as parsed
+/// by [`parse_forge_sync_target`].
+pub struct ForgeSyncTarget {
+    pub provider: Box<dyn RepositoryProvider>,
+    pub org: String,
+}
+
+/// Resolve `forge` (`github`, `gitlab`, `gitea`/`forgejo`) to a concrete
+/// provider, reading each self-hosted forge's base URL and token from its
+/// conventional environment variables (`GITLAB_URL`/`GITLAB_TOKEN`,
+/// `GITEA_URL`/`GITEA_TOKEN`; GitHub always targets `api.github.com` and
+/// reads `GITHUB_TOKEN`). `host`, when given, overrides the forge's `_URL`
+/// env var for callers that name an instance inline (e.g.
+/// `gitea:myorg@git.example.com`) rather than exporting it globally.
+fn resolve_forge_provider(forge: &str, host: Option<&str>) -> Result<Box<dyn RepositoryProvider>> {
+    Ok(match forge {
+        "github" => Box::new(GitHubProvider),
+        "gitlab" => {
+            let base_url = host
+                .map(|h| format!("https://{h}"))
+                .or_else(|| std::env::var("GITLAB_URL").ok())
+                .unwrap_or_else(|| "https://gitlab.com".to_string());
+            let token = std::env::var("GITLAB_TOKEN")
+                .context("GITLAB_TOKEN environment variable not set (required to use GitLab)")?;
+            Box::new(GitLabProvider { base_url, token })
+        }
+        "gitea" | "forgejo" => {
+            let base_url = host
+                .map(|h| format!("https://{h}"))
+                .or_else(|| std::env::var("GITEA_URL").ok())
+                .context("GITEA_URL environment variable not set (or name an instance inline, e.g. 'gitea:account@git.example.com')")?;
+            let token = std::env::var("GITEA_TOKEN")
+                .context("GITEA_TOKEN environment variable not set (required to use Gitea/Forgejo)")?;
+            Box::new(GiteaProvider { base_url, token })
+        }
+        other => anyhow::bail!("Unknown forge '{other}'; expected one of: github, gitlab, gitea, forgejo"),
+    })
+}
+
+/// Parse a `viewyard viewset sync` target of the form `<forge>:<org>` (e.g.
+/// `github:acme`, `gitlab:my-group`, `gitea:acme`) into a provider plus the
+/// org/group to sync.
+pub fn parse_forge_sync_target(spec: &str) -> Result<ForgeSyncTarget> {
+    let (forge, org) = spec.split_once(':').with_context(|| {
+        format!("Invalid sync target '{spec}'; expected '<forge>:<org>', e.g. 'github:acme'")
+    })?;
+
+    if org.is_empty() {
+        anyhow::bail!("Invalid sync target '{spec}': org/group name is empty");
+    }
+
+    Ok(ForgeSyncTarget {
+        provider: resolve_forge_provider(forge, None)?,
+        org: org.to_string(),
+    })
+}
+
+/// A forge-qualified `--account` value (e.g. `gitlab:mygroup`,
+/// `gitea:myorg@git.example.com`) resolved to a provider plus the
+/// account/org name to discover repositories from.
+pub struct ForgeAccount {
+    pub provider: Box<dyn RepositoryProvider>,
+    pub account: String,
+}
+
+/// Parse a `viewset create`/`viewset update` `--account` value. A bare name
+/// with no `<forge>:` prefix targets GitHub, matching viewyard's historical
+/// GitHub-only behavior. `<forge>:<account>` or
+/// `<forge>:<account>@<host>` targets a self-hosted GitLab/Gitea/Forgejo
+/// instance, with `<host>` overriding that forge's `_URL` environment
+/// variable when the caller wants a specific instance without exporting it
+/// globally.
+pub fn parse_forge_account(spec: &str) -> Result<ForgeAccount> {
+    let Some((forge, rest)) = spec.split_once(':') else {
+        return Ok(ForgeAccount {
+            provider: Box::new(GitHubProvider),
+            account: spec.to_string(),
+        });
+    };
+
+    let (account, host) = match rest.split_once('@') {
+        Some((account, host)) => (account, Some(host)),
+        None => (rest, None),
+    };
+
+    if account.is_empty() {
+        anyhow::bail!("Invalid account spec '{spec}': account name is empty");
+    }
+
+    Ok(ForgeAccount {
+        provider: resolve_forge_provider(forge, host)?,
+        account: account.to_string(),
+    })
+}
@@ -0,0 +1,64 @@
+//! Resumable state for `viewyard rebase`.
+//!
+//! A batch rebase across many repos can stop partway through when one hits
+//! a conflict (or any other rebase failure). Rather than losing track of
+//! which repos were already rebased, the in-progress batch is persisted to
+//! `.viewyard-rebase-state.json` in the view root so `viewyard rebase
+//! --continue`/`--abort` can pick up where it left off instead of
+//! re-fetching and re-rebasing repos that already succeeded.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const REBASE_STATE_FILE: &str = ".viewyard-rebase-state.json";
+
+/// A rebase batch that stopped before finishing every repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RebaseState {
+    /// Repos successfully rebased so far, across the original invocation and
+    /// any `--continue` runs.
+    pub rebased: Vec<String>,
+    /// The repo the batch stopped on (conflicted, or failed for some other
+    /// reason) and hasn't been resolved yet.
+    pub stopped_at: String,
+    /// Repos not yet attempted, in the order they'll be processed.
+    pub pending: Vec<String>,
+}
+
+fn state_path(view_root: &Path) -> PathBuf {
+    view_root.join(REBASE_STATE_FILE)
+}
+
+/// Load the in-progress rebase state for `view_root`, if any.
+pub fn load(view_root: &Path) -> Result<Option<RebaseState>> {
+    let path = state_path(view_root);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read rebase state at {}", path.display()))?;
+    let state = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse rebase state at {}", path.display()))?;
+    Ok(Some(state))
+}
+
+/// Persist `state`, overwriting any previous in-progress batch.
+pub fn save(view_root: &Path, state: &RebaseState) -> Result<()> {
+    let path = state_path(view_root);
+    let json = serde_json::to_string_pretty(state)?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write rebase state at {}", path.display()))
+}
+
+/// Remove the in-progress rebase state, e.g. once a batch finishes cleanly
+/// or `--abort` discards it.
+pub fn clear(view_root: &Path) -> Result<()> {
+    let path = state_path(view_root);
+    if path.exists() {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove rebase state at {}", path.display()))?;
+    }
+    Ok(())
+}
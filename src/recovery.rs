@@ -0,0 +1,161 @@
+//! Self-healing recovery for corrupted repository working copies, modeled on
+//! how Cargo recovers a corrupt registry checkout: a narrow allow-list of
+//! failure signatures we know are safe to fix by re-cloning, checked against
+//! an explicit deny-list of network/auth failures that must never be
+//! retried (re-cloning an unreachable host just repeats the same failure).
+
+use crate::git;
+use crate::ui;
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Git failure messages that indicate a corrupted local working copy or
+/// object database - HEAD/ref resolution failing after a successful fetch,
+/// a bad object database, an unreadable config - each fixable by re-cloning.
+const RECOVERABLE_CORRUPTION_SIGNATURES: &[&str] = &[
+    "fatal: bad object",
+    "fatal: not a valid object name",
+    "fatal: unable to resolve HEAD",
+    "fatal: ambiguous argument 'HEAD'",
+    "fatal: your current branch",
+    "error: object file",
+    "fatal: loose object",
+    "fatal: bad config",
+    "error: could not lock config file",
+    "fatal: not a git repository",
+    "fatal: reference is not a tree",
+];
+
+/// Network/DNS/auth failures that must be surfaced as-is, never treated as
+/// recoverable corruption - checked before the recoverable list so a message
+/// that happens to mention both is still classified as non-recoverable.
+const NON_RECOVERABLE_SIGNATURES: &[&str] = &[
+    "Connection reset",
+    "Connection timed out",
+    "Could not resolve host",
+    "Temporary failure in name resolution",
+    "kex_exchange_identification",
+    "Permission denied (publickey)",
+    "Authentication failed",
+    "timed out",
+    " 401 ",
+    " 403 ",
+];
+
+/// Whether `message` (a git failure, `anyhow::Error`'s `{:#}` rendering)
+/// describes a corrupted local working copy recoverable by re-cloning, as
+/// opposed to a network/DNS/auth failure that would just fail again.
+#[must_use]
+pub fn is_recoverable_corruption(message: &str) -> bool {
+    if NON_RECOVERABLE_SIGNATURES
+        .iter()
+        .any(|signature| message.contains(signature))
+    {
+        return false;
+    }
+    RECOVERABLE_CORRUPTION_SIGNATURES
+        .iter()
+        .any(|signature| message.contains(signature))
+}
+
+/// Move the broken working copy aside (never delete outright) and clone
+/// `url` fresh in its place; the quarantine directory is removed once the
+/// re-clone succeeds.
+fn reclone(repo_path: &Path, url: &str) -> Result<()> {
+    let file_name = repo_path
+        .file_name()
+        .context("Repository path has no file name to recover")?
+        .to_string_lossy()
+        .to_string();
+    let quarantine = repo_path.with_file_name(format!(
+        "{file_name}.viewyard-corrupt-{}",
+        std::process::id()
+    ));
+
+    if quarantine.exists() {
+        std::fs::remove_dir_all(&quarantine).with_context(|| {
+            format!(
+                "Failed to clear stale quarantine directory: {}",
+                quarantine.display()
+            )
+        })?;
+    }
+    std::fs::rename(repo_path, &quarantine).with_context(|| {
+        format!(
+            "Failed to move corrupted repository aside: {}",
+            repo_path.display()
+        )
+    })?;
+
+    match git::clone_repository(url, repo_path) {
+        Ok(()) => {
+            let _ = std::fs::remove_dir_all(&quarantine);
+            Ok(())
+        }
+        Err(e) => {
+            // Leave the quarantined copy in place and restore the original
+            // path so the caller's error points at a path that still exists.
+            let _ = std::fs::rename(&quarantine, repo_path);
+            Err(e)
+        }
+    }
+}
+
+/// Run `operation` against `repo_path`. If it fails with a recoverable
+/// corruption signature, re-clone from `url` and retry `operation` exactly
+/// once; any other failure (including the retry's own) is returned as-is.
+pub fn with_recovery<T>(
+    repo_path: &Path,
+    url: &str,
+    mut operation: impl FnMut(&Path) -> Result<T>,
+) -> Result<T> {
+    let err = match operation(repo_path) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+
+    if !is_recoverable_corruption(&format!("{err:#}")) {
+        return Err(err);
+    }
+
+    ui::print_warning(&format!(
+        "Detected a corrupted repository at {} - re-cloning and retrying once",
+        repo_path.display()
+    ));
+    reclone(repo_path, url).context("Failed to recover corrupted repository")?;
+    operation(repo_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recoverable_corruption_signatures_are_flagged() {
+        assert!(is_recoverable_corruption(
+            "fatal: bad object refs/heads/main"
+        ));
+        assert!(is_recoverable_corruption(
+            "fatal: unable to resolve HEAD after fetch"
+        ));
+        assert!(is_recoverable_corruption("fatal: bad config line 3"));
+    }
+
+    #[test]
+    fn test_network_failures_are_never_recoverable() {
+        assert!(!is_recoverable_corruption(
+            "fatal: unable to access 'https://example.com/repo.git': Could not resolve host: example.com"
+        ));
+        assert!(!is_recoverable_corruption(
+            "ssh: connect to host example.com port 22: Connection timed out"
+        ));
+        assert!(!is_recoverable_corruption(
+            "remote: Permission denied (publickey)."
+        ));
+    }
+
+    #[test]
+    fn test_unrelated_errors_are_not_recoverable() {
+        assert!(!is_recoverable_corruption("fatal: pathspec did not match any files"));
+    }
+}
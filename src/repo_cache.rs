@@ -0,0 +1,85 @@
+//! Local snapshot of discovered repositories, so `--offline` selection works
+//! on flaky connections and repeated runs against large accounts skip
+//! re-fetching every time. Mirrors `zvault`'s `BundleDb::load_bundle_list`
+//! online/offline split: the online path always refreshes this cache after a
+//! successful discovery, and the offline path reads it back instead of
+//! hitting the network at all.
+
+use crate::models::Repository;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RepoSnapshot {
+    saved_at: i64,
+    repositories: Vec<Repository>,
+}
+
+fn cache_path() -> Result<PathBuf> {
+    Ok(crate::config::config_dir()?.join("repo_cache.json"))
+}
+
+/// Persist `repositories` as the offline snapshot, overwriting any previous
+/// one. Called after a successful online discovery so the next `--offline`
+/// run has something to read.
+pub fn save_snapshot(repositories: &[Repository]) -> Result<()> {
+    let path = cache_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create cache directory '{}'", parent.display()))?;
+    }
+
+    let saved_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    let snapshot = RepoSnapshot {
+        saved_at,
+        repositories: repositories.to_vec(),
+    };
+
+    let contents = serde_json::to_string_pretty(&snapshot)
+        .context("Failed to serialize repository cache")?;
+    fs::write(&path, contents)
+        .with_context(|| format!("Failed to write repository cache to '{}'", path.display()))?;
+    Ok(())
+}
+
+/// Load the cached snapshot and its age in seconds, or an error telling the
+/// user to run an online discovery first if none exists yet.
+pub fn load_snapshot() -> Result<(Vec<Repository>, i64)> {
+    let path = cache_path()?;
+    let contents = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "No cached repository snapshot found at '{}'; run without --offline at least once first",
+            path.display()
+        )
+    })?;
+
+    let snapshot: RepoSnapshot = serde_json::from_str(&contents)
+        .with_context(|| format!("Failed to parse repository cache at '{}'", path.display()))?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(snapshot.saved_at);
+    let age_secs = (now - snapshot.saved_at).max(0);
+
+    Ok((snapshot.repositories, age_secs))
+}
+
+/// Render an age in seconds as a short human string, e.g. "2h old".
+#[must_use]
+pub fn format_age(age_secs: i64) -> String {
+    if age_secs < 60 {
+        "just now".to_string()
+    } else if age_secs < 3600 {
+        format!("{}m old", age_secs / 60)
+    } else if age_secs < 86400 {
+        format!("{}h old", age_secs / 3600)
+    } else {
+        format!("{}d old", age_secs / 86400)
+    }
+}
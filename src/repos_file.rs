@@ -0,0 +1,312 @@
+//! Reads and writes a viewset's repository list, accepting either the
+//! historical `.viewyard-repos.json` or a `.viewyard-repos.toml` for teams
+//! that keep their repo lists in the more comment-and-diff-friendly TOML
+//! they already use for other tooling.
+//!
+//! [`find_repos_file`] is the single place format detection happens, so
+//! every caller (`detect_viewset_context`, `load_viewset_repositories`,
+//! `sync_viewset`, ...) auto-detects JSON vs. TOML for free instead of
+//! needing its own check.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::models::Repository;
+
+pub const JSON_FILENAME: &str = ".viewyard-repos.json";
+pub const TOML_FILENAME: &str = ".viewyard-repos.toml";
+
+/// Which serialization a viewset's repo list is stored in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoFileFormat {
+    Json,
+    Toml,
+}
+
+impl RepoFileFormat {
+    /// The canonical filename for this format, e.g. `.viewyard-repos.toml`.
+    #[must_use]
+    pub fn filename(self) -> &'static str {
+        match self {
+            Self::Json => JSON_FILENAME,
+            Self::Toml => TOML_FILENAME,
+        }
+    }
+}
+
+/// TOML has no bare top-level array like JSON's `[ {...}, ... ]`, so the
+/// repo list lives under a `[[repos]]` array of tables instead.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct TomlRepoList {
+    #[serde(default)]
+    repos: Vec<Repository>,
+}
+
+/// Locate a viewset's repo list under `viewset_root`, preferring the JSON
+/// file when both happen to exist (matching its historical precedence as
+/// the only format before TOML support was added).
+#[must_use]
+pub fn find_repos_file(viewset_root: &Path) -> Option<(PathBuf, RepoFileFormat)> {
+    let json_path = viewset_root.join(JSON_FILENAME);
+    if json_path.exists() {
+        return Some((json_path, RepoFileFormat::Json));
+    }
+
+    let toml_path = viewset_root.join(TOML_FILENAME);
+    if toml_path.exists() {
+        return Some((toml_path, RepoFileFormat::Toml));
+    }
+
+    None
+}
+
+/// Parse a repo list from `contents`, already known to be in `format`.
+pub fn parse_repos(
+    contents: &str,
+    format: RepoFileFormat,
+    source_path: &Path,
+) -> Result<Vec<Repository>> {
+    match format {
+        RepoFileFormat::Json => serde_json::from_str(contents).with_context(|| {
+            format!(
+                "Invalid JSON in configuration file: {}\n\
+                Expected format: array of repository objects with 'name', 'url', 'is_private', and 'source' fields",
+                source_path.display()
+            )
+        }),
+        RepoFileFormat::Toml => {
+            let parsed: TomlRepoList = toml::from_str(contents).with_context(|| {
+                format!(
+                    "Invalid TOML in configuration file: {}\n\
+                    Expected format: a [[repos]] array of tables with 'name', 'url', 'is_private', and 'source' fields",
+                    source_path.display()
+                )
+            })?;
+            Ok(parsed.repos)
+        }
+    }
+}
+
+/// Serialize `repos` into `format`'s on-disk representation.
+pub fn serialize_repos(repos: &[Repository], format: RepoFileFormat) -> Result<String> {
+    match format {
+        RepoFileFormat::Json => {
+            Ok(serde_json::to_string_pretty(repos).context("Failed to serialize repository list as JSON")?)
+        }
+        RepoFileFormat::Toml => {
+            let wrapped = TomlRepoList {
+                repos: repos.to_vec(),
+            };
+            toml::to_string_pretty(&wrapped).context("Failed to serialize repository list as TOML")
+        }
+    }
+}
+
+/// Canonicalize a repo list before writing it back to disk: dedupe by name
+/// (case-insensitively, since forges won't let two repos in the same
+/// namespace differ only by case - first occurrence wins) and sort the
+/// result by name, so repeated `viewset update` runs produce the same
+/// file byte-for-byte instead of reordering entries based on whatever order
+/// they were discovered or merged in.
+#[must_use]
+pub fn canonicalize_repos(mut repos: Vec<Repository>) -> Vec<Repository> {
+    let mut seen = std::collections::HashSet::new();
+    repos.retain(|repo| seen.insert(repo.name.to_lowercase()));
+    repos.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    repos
+}
+
+/// A minimal `+`/`-` line diff between `old` and `new` file contents, for
+/// previewing what a write would change (e.g. `viewset update --dry-run`)
+/// without a crate dependency. Unchanged lines are kept for context rather
+/// than reprinting the whole file as removed-then-added.
+#[must_use]
+pub fn diff_lines(old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            out.push_str("  ");
+            out.push_str(old_lines[i]);
+            out.push('\n');
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push('-');
+            out.push_str(old_lines[i]);
+            out.push('\n');
+            i += 1;
+        } else {
+            out.push('+');
+            out.push_str(new_lines[j]);
+            out.push('\n');
+            j += 1;
+        }
+    }
+    for line in &old_lines[i..n] {
+        out.push('-');
+        out.push_str(line);
+        out.push('\n');
+    }
+    for line in &new_lines[j..m] {
+        out.push('+');
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Run the same structural checks regardless of source format: non-empty
+/// `name`/`url`, and a warning (not an error) for URLs that don't look like
+/// a git remote.
+pub fn validate_repos(repos: &[Repository], source_path: &Path) -> Result<()> {
+    for (index, repo) in repos.iter().enumerate() {
+        if repo.name.trim().is_empty() {
+            anyhow::bail!(
+                "Invalid repository at index {}: 'name' field cannot be empty\n\
+                File: {}",
+                index,
+                source_path.display()
+            );
+        }
+
+        if repo.url.trim().is_empty() {
+            anyhow::bail!(
+                "Invalid repository at index {}: 'url' field cannot be empty\n\
+                Repository: {}\n\
+                File: {}",
+                index,
+                repo.name,
+                source_path.display()
+            );
+        }
+
+        // Basic URL validation - should contain git-like patterns
+        if !repo.url.contains("git") && !repo.url.contains("github") && !repo.url.contains("gitlab")
+        {
+            crate::ui::print_warning(&format!(
+                "Repository '{}' has unusual URL format: {}\n\
+                This might not be a valid Git repository URL",
+                repo.name, repo.url
+            ));
+        }
+
+        if let crate::vcs::Backend::Unknown(name) = crate::vcs::Backend::from_repo(repo) {
+            anyhow::bail!(
+                "Invalid repository at index {}: unsupported 'backend' value '{}'\n\
+                Repository: {}\n\
+                File: {}",
+                index,
+                name,
+                repo.name,
+                source_path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Read, parse, and validate a viewset's repo list from `path` (format
+/// inferred from its extension).
+pub fn load_and_validate(path: &Path) -> Result<Vec<Repository>> {
+    let format = format_for_path(path);
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read configuration file: {}", path.display()))?;
+    let repos = parse_repos(&contents, format, path)?;
+    validate_repos(&repos, path)?;
+    Ok(repos)
+}
+
+fn format_for_path(path: &Path) -> RepoFileFormat {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => RepoFileFormat::Toml,
+        _ => RepoFileFormat::Json,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_repo(name: &str) -> Repository {
+        Repository {
+            name: name.to_string(),
+            url: format!("https://github.com/test/{name}"),
+            is_private: false,
+            source: "GitHub (dheater)".to_string(),
+            account: None,
+            category: None,
+            backend: None,
+            topics: Vec::new(),
+            language: None,
+            clone_strategy: None,
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_repos_dedupes_case_insensitively_keeping_first() {
+        let repos = vec![
+            create_test_repo("service-auth"),
+            create_test_repo("Service-Auth"),
+            create_test_repo("library-core"),
+        ];
+
+        let canonical = canonicalize_repos(repos);
+        let names: Vec<&str> = canonical.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(names, vec!["library-core", "service-auth"]);
+    }
+
+    #[test]
+    fn test_canonicalize_repos_sorts_by_name_case_insensitively() {
+        let repos = vec![
+            create_test_repo("zeta"),
+            create_test_repo("Alpha"),
+            create_test_repo("mu"),
+        ];
+
+        let canonical = canonicalize_repos(repos);
+        let names: Vec<&str> = canonical.iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(names, vec!["Alpha", "mu", "zeta"]);
+    }
+
+    #[test]
+    fn test_serialize_repos_json_round_trip_is_byte_for_byte_stable() {
+        let repos = canonicalize_repos(vec![create_test_repo("library-core"), create_test_repo("service-auth")]);
+
+        let first_pass = serialize_repos(&repos, RepoFileFormat::Json).unwrap();
+        let reparsed =
+            parse_repos(&first_pass, RepoFileFormat::Json, Path::new(".viewyard-repos.json")).unwrap();
+        let second_pass = serialize_repos(&canonicalize_repos(reparsed), RepoFileFormat::Json).unwrap();
+
+        assert_eq!(first_pass, second_pass);
+    }
+
+    #[test]
+    fn test_diff_lines_marks_additions_and_removals_around_context() {
+        let old = "a\nb\nc\n";
+        let new = "a\nx\nc\n";
+
+        let diff = diff_lines(old, new);
+
+        assert_eq!(diff, "  a\n-b\n+x\n  c\n");
+    }
+}
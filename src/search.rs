@@ -1,6 +1,150 @@
 use crate::models::Repository;
+use anyhow::{Context, Result};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
+use globset::{Glob, GlobMatcher};
+
+/// Gitignore-style include/exclude filter over repository names (and,
+/// optionally, their source). Patterns are applied in order with
+/// last-match-wins semantics: a leading `!` negates a pattern, so
+/// `["service-*", "!*-deprecated"]` includes everything matching
+/// `service-*` except names also matching `*-deprecated`.
+pub struct RepoFilter {
+    patterns: Vec<(GlobMatcher, bool)>, // (matcher, include)
+}
+
+impl RepoFilter {
+    /// Compile an ordered list of glob patterns into a filter. A pattern
+    /// prefixed with `!` negates (excludes) matches; otherwise it includes.
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut compiled = Vec::with_capacity(patterns.len());
+
+        for pattern in patterns {
+            let (include, raw) = pattern
+                .strip_prefix('!')
+                .map_or((true, pattern.as_str()), |rest| (false, rest));
+
+            let glob = Glob::new(raw)
+                .with_context(|| format!("Invalid glob pattern: '{pattern}'"))?
+                .compile_matcher();
+            compiled.push((glob, include));
+        }
+
+        Ok(Self { patterns: compiled })
+    }
+
+    /// Returns true if `repo` should be kept. With no patterns configured,
+    /// everything is kept. Otherwise the last matching pattern wins; a repo
+    /// that matches nothing is excluded (the filter is allow-list by default
+    /// once any pattern is given).
+    #[must_use]
+    pub fn matches(&self, repo: &Repository) -> bool {
+        if self.patterns.is_empty() {
+            return true;
+        }
+
+        let mut keep = false;
+        let mut matched_any = false;
+        for (matcher, include) in &self.patterns {
+            if matcher.is_match(&repo.name) || matcher.is_match(&repo.source) {
+                matched_any = true;
+                keep = *include;
+            }
+        }
+
+        matched_any && keep
+    }
+
+    /// Filter a slice of repositories down to the ones this filter keeps.
+    #[must_use]
+    pub fn filter(&self, repositories: &[Repository]) -> Vec<Repository> {
+        repositories
+            .iter()
+            .filter(|repo| self.matches(repo))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Narrows discovered repositories by forge metadata before they reach the
+/// interactive picker - `--topic`/`--language` match [`Repository::topics`]/
+/// [`Repository::language`] exactly (case-insensitively, since forges are
+/// inconsistent about casing), while `--search` fuzzy-matches name/source
+/// the same way [`filter_repositories`] does. All three are optional and
+/// compose as an AND: a repo must pass every criterion given.
+#[derive(Default)]
+pub struct DiscoveryFilter {
+    topic: Option<String>,
+    language: Option<String>,
+    search: Option<String>,
+}
+
+impl DiscoveryFilter {
+    #[must_use]
+    pub fn new(topic: Option<String>, language: Option<String>, search: Option<String>) -> Self {
+        Self { topic, language, search }
+    }
+
+    /// True when no criteria were given, so callers can skip filtering
+    /// entirely rather than cloning the whole list through a no-op pass.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.topic.is_none() && self.language.is_none() && self.search.is_none()
+    }
+
+    #[must_use]
+    pub fn apply(&self, repositories: &[Repository]) -> Vec<Repository> {
+        if self.is_empty() {
+            return repositories.to_vec();
+        }
+
+        let mut kept = repositories.to_vec();
+
+        if let Some(topic) = &self.topic {
+            kept.retain(|repo| repo.topics.iter().any(|t| t.eq_ignore_ascii_case(topic)));
+        }
+
+        if let Some(language) = &self.language {
+            kept.retain(|repo| repo.language.as_deref().is_some_and(|l| l.eq_ignore_ascii_case(language)));
+        }
+
+        if let Some(search) = &self.search {
+            let matcher = SkimMatcherV2::default();
+            kept.retain(|repo| {
+                matcher.fuzzy_match(&repo.name, search).is_some()
+                    || matcher.fuzzy_match(&repo.source, search).is_some()
+            });
+        }
+
+        kept
+    }
+}
+
+/// Fuzzy-filter repositories by `query`, scoring against both `name` and
+/// `source` and keeping the best of the two per repo. Uses the same
+/// subsequence matcher as [`RepositorySearch`] (consecutive-match and
+/// word-boundary bonuses, gap penalties), exposed as a free function so
+/// callers that just need a quick non-interactive narrowing don't have to
+/// construct a [`RepositorySearch`].
+#[must_use]
+pub fn filter_repositories(repositories: &[Repository], query: &str) -> Vec<(Repository, i64)> {
+    if query.trim().is_empty() {
+        return repositories.iter().map(|repo| (repo.clone(), 0)).collect();
+    }
+
+    let matcher = SkimMatcherV2::default();
+    let mut matches: Vec<(Repository, i64)> = repositories
+        .iter()
+        .filter_map(|repo| {
+            let name_score = matcher.fuzzy_match(&repo.name, query);
+            let source_score = matcher.fuzzy_match(&repo.source, query);
+            name_score.into_iter().chain(source_score).max().map(|score| (repo.clone(), score))
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.1.cmp(&a.1));
+    matches
+}
 
 pub struct RepositorySearch {
     matcher: SkimMatcherV2,
@@ -34,6 +178,20 @@ impl RepositorySearch {
         matches
     }
 
+    /// Apply an include/exclude glob filter before fuzzy-scoring against `query`,
+    /// so a large discovery can be scoped down (e.g. to `service-*` repos) while
+    /// keeping the remaining results ranked by relevance.
+    #[must_use]
+    pub fn search_filtered(
+        &self,
+        repositories: &[Repository],
+        filter: &RepoFilter,
+        query: &str,
+    ) -> Vec<(Repository, i64)> {
+        let filtered = filter.filter(repositories);
+        self.search(&filtered, query)
+    }
+
     /// Group repositories by source for better display
     pub fn group_by_source(
         repositories: &[Repository],
@@ -41,37 +199,97 @@ impl RepositorySearch {
         let mut groups = std::collections::BTreeMap::new();
 
         for repo in repositories {
-            let source_key = if repo.source.contains("GitHub (") {
-                // Extract account/org from "GitHub (account)" or "GitHub (org/account)"
-                repo.source.find("GitHub (").map_or_else(
-                    || repo.source.clone(),
-                    |start| {
-                        let after_github = &repo.source[start + 8..];
-                        after_github.find(')').map_or_else(
-                            || repo.source.clone(),
-                            |end| {
-                                let account_part = &after_github[..end];
-                                if account_part.contains('/') {
-                                    // Organization repo: "org/account"
-                                    let org =
-                                        account_part.split('/').next().unwrap_or(account_part);
-                                    format!("GitHub ({org})")
-                                } else {
-                                    // Personal repo: "account"
-                                    format!("GitHub ({account_part})")
-                                }
-                            },
-                        )
-                    },
-                )
+            groups
+                .entry(Self::group_key(&repo.source))
+                .or_insert_with(Vec::new)
+                .push(repo.clone());
+        }
+
+        groups
+    }
+
+    /// Group repositories by their [`Repository::category`] tag, for the
+    /// "select everything tagged `backend`" use case. Repos without a
+    /// category are omitted rather than lumped into an "uncategorized"
+    /// bucket, since most repos won't have one set.
+    pub fn group_by_category(
+        repositories: &[Repository],
+    ) -> std::collections::BTreeMap<String, Vec<Repository>> {
+        let mut groups = std::collections::BTreeMap::new();
+
+        for repo in repositories {
+            if let Some(category) = &repo.category {
+                groups
+                    .entry(category.clone())
+                    .or_insert_with(Vec::new)
+                    .push(repo.clone());
+            }
+        }
+
+        groups
+    }
+
+    /// Collapse a source label down to its grouping key: org-level for
+    /// GitHub org repos, account-level for personal ones, verbatim otherwise.
+    fn group_key(source: &str) -> String {
+        if source.contains("GitHub (") {
+            // Extract account/org from "GitHub (account)" or "GitHub (org/account)"
+            source.find("GitHub (").map_or_else(
+                || source.to_string(),
+                |start| {
+                    let after_github = &source[start + 8..];
+                    after_github.find(')').map_or_else(
+                        || source.to_string(),
+                        |end| {
+                            let account_part = &after_github[..end];
+                            if account_part.contains('/') {
+                                // Organization repo: "org/account"
+                                let org = account_part.split('/').next().unwrap_or(account_part);
+                                format!("GitHub ({org})")
+                            } else {
+                                // Personal repo: "account"
+                                format!("GitHub ({account_part})")
+                            }
+                        },
+                    )
+                },
+            )
+        } else {
+            source.to_string()
+        }
+    }
+
+    /// Fuzzy-score repositories by name, group them the same way
+    /// [`Self::group_by_source`] does, and sort each group by descending Skim
+    /// score with the matched character indices attached - the single entry
+    /// point the picker TUI re-runs on every keystroke.
+    #[must_use]
+    pub fn search_grouped(
+        &self,
+        repositories: &[Repository],
+        query: &str,
+    ) -> std::collections::BTreeMap<String, Vec<(Repository, i64, Vec<usize>)>> {
+        let mut groups: std::collections::BTreeMap<String, Vec<(Repository, i64, Vec<usize>)>> =
+            std::collections::BTreeMap::new();
+
+        for repo in repositories {
+            let (score, indices) = if query.trim().is_empty() {
+                (0, Vec::new())
             } else {
-                repo.source.clone()
+                match self.matcher.fuzzy_indices(&repo.name, query) {
+                    Some((score, indices)) => (score, indices),
+                    None => continue,
+                }
             };
 
             groups
-                .entry(source_key)
-                .or_insert_with(Vec::new)
-                .push(repo.clone());
+                .entry(Self::group_key(&repo.source))
+                .or_default()
+                .push((repo.clone(), score, indices));
+        }
+
+        for group in groups.values_mut() {
+            group.sort_by(|a, b| b.1.cmp(&a.1));
         }
 
         groups
@@ -94,9 +312,107 @@ mod tests {
             url: format!("https://github.com/test/{name}"),
             is_private: false,
             source: source.to_string(),
+            account: None,
+            category: None,
+            backend: None,
+            topics: Vec::new(),
+            language: None,
+            clone_strategy: None,
         }
     }
 
+    #[test]
+    fn test_repo_filter_include_and_negate() {
+        let repos = vec![
+            create_test_repo("service-auth", "GitHub (dheater)"),
+            create_test_repo("service-billing-deprecated", "GitHub (dheater)"),
+            create_test_repo("library-core", "GitHub (dheater)"),
+        ];
+
+        let filter = RepoFilter::new(&["service-*".to_string(), "!*-deprecated".to_string()]).unwrap();
+        let kept: Vec<&str> = filter.filter(&repos).iter().map(|r| r.name.as_str()).collect();
+
+        assert_eq!(kept, vec!["service-auth"]);
+    }
+
+    #[test]
+    fn test_repo_filter_no_patterns_keeps_everything() {
+        let repos = vec![create_test_repo("anything", "GitHub (dheater)")];
+        let filter = RepoFilter::new(&[]).unwrap();
+        assert_eq!(filter.filter(&repos).len(), 1);
+    }
+
+    fn create_test_repo_with_metadata(
+        name: &str,
+        topics: &[&str],
+        language: Option<&str>,
+    ) -> Repository {
+        let mut repo = create_test_repo(name, "GitHub (dheater)");
+        repo.topics = topics.iter().map(|t| t.to_string()).collect();
+        repo.language = language.map(str::to_string);
+        repo
+    }
+
+    #[test]
+    fn test_discovery_filter_empty_keeps_everything() {
+        let repos = vec![create_test_repo("anything", "GitHub (dheater)")];
+        assert!(DiscoveryFilter::default().is_empty());
+        assert_eq!(DiscoveryFilter::default().apply(&repos).len(), 1);
+    }
+
+    #[test]
+    fn test_discovery_filter_by_topic_is_case_insensitive() {
+        let repos = vec![
+            create_test_repo_with_metadata("svc-a", &["backend", "rust"], None),
+            create_test_repo_with_metadata("svc-b", &["frontend"], None),
+        ];
+
+        let filter = DiscoveryFilter::new(Some("Backend".to_string()), None, None);
+        let kept: Vec<&str> = filter.apply(&repos).iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(kept, vec!["svc-a"]);
+    }
+
+    #[test]
+    fn test_discovery_filter_by_language_and_topic_is_an_and() {
+        let repos = vec![
+            create_test_repo_with_metadata("svc-a", &["backend"], Some("Rust")),
+            create_test_repo_with_metadata("svc-b", &["backend"], Some("Python")),
+            create_test_repo_with_metadata("svc-c", &["frontend"], Some("Rust")),
+        ];
+
+        let filter = DiscoveryFilter::new(Some("backend".to_string()), Some("rust".to_string()), None);
+        let kept: Vec<&str> = filter.apply(&repos).iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(kept, vec!["svc-a"]);
+    }
+
+    #[test]
+    fn test_discovery_filter_by_search_matches_name() {
+        let repos = vec![
+            create_test_repo("service-auth", "GitHub (dheater)"),
+            create_test_repo("library-core", "GitHub (dheater)"),
+        ];
+
+        let filter = DiscoveryFilter::new(None, None, Some("auth".to_string()));
+        let kept: Vec<&str> = filter.apply(&repos).iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(kept, vec!["service-auth"]);
+    }
+
+    #[test]
+    fn test_filter_repositories_matches_name_or_source() {
+        let repos = vec![
+            create_test_repo("frontend-app", "GitHub (dheater)"),
+            create_test_repo("backend-app", "GitHub (acme-corp)"),
+        ];
+
+        let by_name = filter_repositories(&repos, "frontend");
+        assert_eq!(by_name.len(), 1);
+        assert_eq!(by_name[0].0.name, "frontend-app");
+
+        let by_source = filter_repositories(&repos, "acme");
+        assert_eq!(by_source.len(), 1);
+        assert_eq!(by_source[0].0.name, "backend-app");
+    }
+
     #[test]
     fn test_fuzzy_search() {
         let search = RepositorySearch::new();
@@ -124,4 +440,23 @@ mod tests {
         assert!(groups.contains_key("GitHub (dheater)"));
         assert!(groups.contains_key("GitHub (imprivata)"));
     }
+
+    #[test]
+    fn test_search_grouped_scores_and_groups_matches() {
+        let search = RepositorySearch::new();
+        let repos = vec![
+            create_test_repo("service-auth", "GitHub (dheater)"),
+            create_test_repo("service-billing", "GitHub (dheater)"),
+            create_test_repo("library-core", "GitHub (imprivata/dheater)"),
+        ];
+
+        let groups = search.search_grouped(&repos, "auth");
+        assert_eq!(groups.len(), 1);
+        let matches = &groups["GitHub (dheater)"];
+        // Only "service-auth" contains "auth" as a subsequence -
+        // "service-billing" and "library-core" are filtered out entirely.
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].0.name, "service-auth");
+        assert!(!matches[0].2.is_empty());
+    }
 }
@@ -0,0 +1,421 @@
+//! A small revset-style boolean expression language (borrowing jujutsu's
+//! revset idea) for choosing which of a view's repos a workspace command
+//! acts on via `--select <expr>`, instead of always operating on all of
+//! them.
+//!
+//! Grammar, lowest to highest precedence:
+//!   expr  := or
+//!   or    := and ('|' and)*
+//!   and   := unary ('&' unary)*
+//!   unary := '!' unary | atom
+//!   atom  := '(' or ')' | predicate
+//!
+//! Predicates: `dirty`, `ahead`, `stashed`, `branch(NAME)`, `name(GLOB)`,
+//! `private`, `public`. An empty/absent expression matches everything.
+
+use anyhow::Result;
+use globset::Glob;
+use std::cell::RefCell;
+use std::path::Path;
+
+use crate::backend::GitBackend;
+use crate::models::Repository;
+
+/// A leaf condition a repo either satisfies or doesn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Predicate {
+    Dirty,
+    Ahead,
+    Stashed,
+    Branch(String),
+    Name(String),
+    Private,
+    Public,
+}
+
+/// A parsed `--select` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorExpr {
+    /// No expression was given; matches every repo.
+    All,
+    And(Box<SelectorExpr>, Box<SelectorExpr>),
+    Or(Box<SelectorExpr>, Box<SelectorExpr>),
+    Not(Box<SelectorExpr>),
+    Predicate(Predicate),
+}
+
+/// Parse a `--select` expression. `None` or a blank string matches
+/// everything, mirroring the "absent selector = no filtering" default other
+/// viewyard flags use.
+pub fn parse_selector(input: Option<&str>) -> Result<SelectorExpr> {
+    let Some(input) = input else {
+        return Ok(SelectorExpr::All);
+    };
+    if input.trim().is_empty() {
+        return Ok(SelectorExpr::All);
+    }
+
+    let mut parser = Parser::new(input);
+    let expr = parser.parse_or()?;
+    parser.skip_ws();
+    if parser.pos < parser.chars.len() {
+        return Err(parser.error("unexpected trailing input"));
+    }
+    Ok(expr)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chars: input.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn error(&self, msg: &str) -> anyhow::Error {
+        anyhow::anyhow!("{msg} at position {} in selector '{}'", self.pos, self.input)
+    }
+
+    fn parse_or(&mut self) -> Result<SelectorExpr> {
+        let mut left = self.parse_and()?;
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('|') {
+                self.pos += 1;
+                let right = self.parse_and()?;
+                left = SelectorExpr::Or(Box::new(left), Box::new(right));
+            } else {
+                break;
+            }
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<SelectorExpr> {
+        let mut left = self.parse_unary()?;
+        loop {
+            self.skip_ws();
+            if self.peek() == Some('&') {
+                self.pos += 1;
+                let right = self.parse_unary()?;
+                left = SelectorExpr::And(Box::new(left), Box::new(right));
+            } else {
+                break;
+            }
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<SelectorExpr> {
+        self.skip_ws();
+        if self.peek() == Some('!') {
+            self.pos += 1;
+            let inner = self.parse_unary()?;
+            return Ok(SelectorExpr::Not(Box::new(inner)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> Result<SelectorExpr> {
+        self.skip_ws();
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.parse_or()?;
+                self.skip_ws();
+                if self.peek() != Some(')') {
+                    return Err(self.error("expected ')'"));
+                }
+                self.pos += 1;
+                Ok(expr)
+            }
+            Some(c) if c.is_alphanumeric() || c == '_' => self.parse_predicate(),
+            _ => Err(self.error("expected a predicate or '('")),
+        }
+    }
+
+    fn parse_ident(&mut self) -> String {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_alphanumeric() || c == '_' || c == '-') {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn parse_predicate(&mut self) -> Result<SelectorExpr> {
+        let error_pos = self.pos;
+        let ident = self.parse_ident();
+        self.skip_ws();
+
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            let arg_start = self.pos;
+            while matches!(self.peek(), Some(c) if c != ')') {
+                self.pos += 1;
+            }
+            if self.peek() != Some(')') {
+                return Err(self.error("expected ')' to close predicate argument"));
+            }
+            let arg: String = self.chars[arg_start..self.pos].iter().collect();
+            self.pos += 1;
+
+            let predicate = match ident.as_str() {
+                "branch" => Predicate::Branch(arg.trim().to_string()),
+                "name" => Predicate::Name(arg.trim().to_string()),
+                other => {
+                    self.pos = error_pos;
+                    return Err(self.error(&format!("'{other}' does not take an argument")));
+                }
+            };
+            return Ok(SelectorExpr::Predicate(predicate));
+        }
+
+        let predicate = match ident.as_str() {
+            "dirty" => Predicate::Dirty,
+            "ahead" => Predicate::Ahead,
+            "stashed" => Predicate::Stashed,
+            "private" => Predicate::Private,
+            "public" => Predicate::Public,
+            "" => {
+                self.pos = error_pos;
+                return Err(self.error("expected a predicate"));
+            }
+            other => {
+                self.pos = error_pos;
+                return Err(self.error(&format!("unknown predicate '{other}'")));
+            }
+        };
+        Ok(SelectorExpr::Predicate(predicate))
+    }
+}
+
+/// Per-repo git facts, computed lazily and cached so evaluating e.g. `dirty
+/// & ahead` runs each underlying git check at most once per repo.
+pub struct RepoFacts<'a> {
+    backend: &'a dyn GitBackend,
+    repo: &'a Repository,
+    repo_path: &'a Path,
+    branch: RefCell<Option<String>>,
+    dirty: RefCell<Option<bool>>,
+    ahead: RefCell<Option<bool>>,
+    stash_count: RefCell<Option<usize>>,
+}
+
+impl<'a> RepoFacts<'a> {
+    #[must_use]
+    pub fn new(backend: &'a dyn GitBackend, repo: &'a Repository, repo_path: &'a Path) -> Self {
+        Self {
+            backend,
+            repo,
+            repo_path,
+            branch: RefCell::new(None),
+            dirty: RefCell::new(None),
+            ahead: RefCell::new(None),
+            stash_count: RefCell::new(None),
+        }
+    }
+
+    fn branch(&self) -> String {
+        if let Some(cached) = self.branch.borrow().clone() {
+            return cached;
+        }
+        let branch = self.backend.get_current_branch(self.repo_path).unwrap_or_default();
+        *self.branch.borrow_mut() = Some(branch.clone());
+        branch
+    }
+
+    fn dirty(&self) -> bool {
+        if let Some(cached) = *self.dirty.borrow() {
+            return cached;
+        }
+        let dirty = self.backend.has_uncommitted_changes(self.repo_path).unwrap_or(false);
+        *self.dirty.borrow_mut() = Some(dirty);
+        dirty
+    }
+
+    fn ahead(&self) -> bool {
+        if let Some(cached) = *self.ahead.borrow() {
+            return cached;
+        }
+        let ahead = self.backend.has_unpushed_commits(self.repo_path).unwrap_or(false);
+        *self.ahead.borrow_mut() = Some(ahead);
+        ahead
+    }
+
+    fn stash_count(&self) -> usize {
+        if let Some(cached) = *self.stash_count.borrow() {
+            return cached;
+        }
+        let count = self.backend.get_stash_count(self.repo_path).unwrap_or(0);
+        *self.stash_count.borrow_mut() = Some(count);
+        count
+    }
+}
+
+impl SelectorExpr {
+    /// Evaluate this expression against `facts`, short-circuiting `&`/`|`
+    /// the way Rust's `&&`/`||` do.
+    #[must_use]
+    pub fn matches(&self, facts: &RepoFacts) -> bool {
+        match self {
+            Self::All => true,
+            Self::And(left, right) => left.matches(facts) && right.matches(facts),
+            Self::Or(left, right) => left.matches(facts) || right.matches(facts),
+            Self::Not(inner) => !inner.matches(facts),
+            Self::Predicate(predicate) => predicate.matches(facts),
+        }
+    }
+}
+
+impl Predicate {
+    fn matches(&self, facts: &RepoFacts) -> bool {
+        match self {
+            Self::Dirty => facts.dirty(),
+            Self::Ahead => facts.ahead(),
+            Self::Stashed => facts.stash_count() > 0,
+            Self::Branch(name) => facts.branch() == *name,
+            Self::Name(glob) => Glob::new(glob)
+                .map(|g| g.compile_matcher().is_match(&facts.repo.name))
+                .unwrap_or(false),
+            Self::Private => facts.repo.is_private,
+            Self::Public => !facts.repo.is_private,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::mock::MockBackend;
+    use crate::backend::RepoStatus;
+
+    fn repo(name: &str, is_private: bool) -> Repository {
+        Repository {
+            name: name.to_string(),
+            url: format!("git@github.com:acme/{name}.git"),
+            is_private,
+            source: "GitHub (acme)".to_string(),
+            account: None,
+            category: None,
+            backend: None,
+            topics: Vec::new(),
+            language: None,
+            clone_strategy: None,
+        }
+    }
+
+    #[test]
+    fn test_empty_selector_matches_everything() {
+        let expr = parse_selector(None).unwrap();
+        assert_eq!(expr, SelectorExpr::All);
+        let expr = parse_selector(Some("  ")).unwrap();
+        assert_eq!(expr, SelectorExpr::All);
+    }
+
+    #[test]
+    fn test_and_or_not_precedence() {
+        // `!private | dirty & ahead` should parse as `(!private) | (dirty & ahead)`.
+        let expr = parse_selector(Some("!private | dirty & ahead")).unwrap();
+        assert_eq!(
+            expr,
+            SelectorExpr::Or(
+                Box::new(SelectorExpr::Not(Box::new(SelectorExpr::Predicate(Predicate::Private)))),
+                Box::new(SelectorExpr::And(
+                    Box::new(SelectorExpr::Predicate(Predicate::Dirty)),
+                    Box::new(SelectorExpr::Predicate(Predicate::Ahead)),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn test_parenthesized_grouping() {
+        let expr = parse_selector(Some("(dirty | ahead) & !stashed")).unwrap();
+        assert_eq!(
+            expr,
+            SelectorExpr::And(
+                Box::new(SelectorExpr::Or(
+                    Box::new(SelectorExpr::Predicate(Predicate::Dirty)),
+                    Box::new(SelectorExpr::Predicate(Predicate::Ahead)),
+                )),
+                Box::new(SelectorExpr::Not(Box::new(SelectorExpr::Predicate(Predicate::Stashed)))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_branch_and_name_predicates_with_args() {
+        let expr = parse_selector(Some("branch(main) & name(service-*)")).unwrap();
+        assert_eq!(
+            expr,
+            SelectorExpr::And(
+                Box::new(SelectorExpr::Predicate(Predicate::Branch("main".to_string()))),
+                Box::new(SelectorExpr::Predicate(Predicate::Name("service-*".to_string()))),
+            )
+        );
+    }
+
+    #[test]
+    fn test_unknown_predicate_reports_position() {
+        let err = parse_selector(Some("bogus")).unwrap_err();
+        assert!(err.to_string().contains("position 0"));
+    }
+
+    #[test]
+    fn test_unclosed_paren_is_an_error() {
+        assert!(parse_selector(Some("(dirty")).is_err());
+    }
+
+    #[test]
+    fn test_matches_is_stable_across_repeated_evaluation() {
+        let path = Path::new("/nonexistent/cached-repo");
+        let backend = MockBackend::default().with_status(
+            path,
+            RepoStatus {
+                branch: "main".to_string(),
+                dirty: true,
+                ahead: 1,
+                behind: 0,
+            },
+        );
+        let repo = repo("cached-repo", false);
+        let facts = RepoFacts::new(&backend, &repo, path);
+
+        let expr = parse_selector(Some("dirty & ahead")).unwrap();
+        assert!(expr.matches(&facts));
+        // Calling again takes the cached path. The mock does not count
+        // calls, so this checks only that repeated evaluation gives the same
+        // result, not how often the backend was queried.
+        assert!(expr.matches(&facts));
+    }
+
+    #[test]
+    fn test_private_public_and_name_glob() {
+        let path = Path::new("/nonexistent/service-api");
+        let backend = MockBackend::default();
+        let private_repo = repo("service-api", true);
+        let facts = RepoFacts::new(&backend, &private_repo, path);
+
+        assert!(parse_selector(Some("private")).unwrap().matches(&facts));
+        assert!(!parse_selector(Some("public")).unwrap().matches(&facts));
+        assert!(parse_selector(Some("name(service-*)")).unwrap().matches(&facts));
+        assert!(!parse_selector(Some("name(worker-*)")).unwrap().matches(&facts));
+    }
+}
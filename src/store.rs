@@ -0,0 +1,271 @@
+//! Shared bare-mirror object store backing cheap view creation via git worktrees.
+//!
+//! A full `git clone` per view means N views of the same viewset pay for N
+//! copies of that repo's entire object database. Instead, each repo gets one
+//! bare mirror clone under `<viewset_root>/.viewyard-store/<cache_key>.git`
+//! (see [`crate::models::cache_key_for_url`]), and every view materializes
+//! its copy of the repo as a `git worktree` pointing back into that shared
+//! mirror - cheap to create and sharing disk with every other view of the
+//! same repo.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::models::CloneStrategy;
+use crate::vcs::BranchSetupOutcome;
+
+/// Directory holding the per-repo bare mirrors for a viewset.
+fn store_dir(viewset_root: &Path) -> PathBuf {
+    viewset_root.join(".viewyard-store")
+}
+
+/// Path to a repo's bare mirror clone within the viewset's shared store,
+/// keyed by [`crate::models::cache_key_for_url`] rather than its display
+/// name - a rename upstream doesn't orphan the cache, and two repos that
+/// happen to share a name across different forges/accounts don't collide.
+pub fn mirror_path(viewset_root: &Path, cache_key: &str) -> PathBuf {
+    store_dir(viewset_root).join(format!("{cache_key}.git"))
+}
+
+/// Ensure a bare mirror of `url` exists under the viewset's shared store,
+/// cloning it if this is the first view that needs its history. Returns the
+/// mirror's path.
+///
+/// An existing mirror is reused as-is with no network access - like
+/// cargo's git database, the mirror is assumed to already hold everything a
+/// new view's worktree needs (it was populated by the clone that created
+/// it), so creating another view never pays for an implicit fetch. There is
+/// currently no explicit "refresh this mirror" operation; a repo's mirror
+/// only gets new upstream history the next time it's cloned fresh (e.g.
+/// under a new [`crate::models::cache_key_for_url`] if its URL changes).
+///
+/// `strategy`, when given, narrows the *initial* clone (depth, single
+/// branch, blobless partial fetch) and has no effect once a mirror already
+/// exists - deepening/widening an existing shallow or partial mirror is a
+/// separate, explicit operation this function doesn't attempt.
+///
+/// `on_progress`, when given, is called with each transfer stage update
+/// parsed from the clone's `--progress` output (see
+/// [`crate::git::GitProgress`]) - unused when the mirror already exists,
+/// since that path makes no network call to report progress on.
+///
+/// `preferred_auth` pins the clone's transport (see
+/// [`crate::credentials::AuthMethod`]). On `Auto`, an SSH URL that fails
+/// with [`crate::git::GitError::SshAuth`] is retried once over HTTPS with a
+/// GitHub token before [`crate::error_handling::handle_clone_error`] shows
+/// manual remediation steps - so a missing/misconfigured SSH key doesn't
+/// block a clone when a `gh`/`GITHUB_TOKEN` credential would have worked.
+pub fn ensure_mirror(
+    url: &str,
+    viewset_root: &Path,
+    name: &str,
+    branch_name: &str,
+    strategy: Option<&CloneStrategy>,
+    on_progress: Option<&dyn Fn(crate::git::GitProgress)>,
+    preferred_auth: crate::credentials::AuthMethod,
+) -> Result<PathBuf> {
+    let cache_key = crate::models::cache_key_for_url(url);
+    let mirror = mirror_path(viewset_root, &cache_key);
+    if mirror.exists() {
+        return Ok(mirror);
+    }
+
+    if crate::git::exec_mode() == crate::git::ExecMode::DryRun {
+        crate::ui::print_info(&format!(
+            "would run: git clone --mirror {} {}",
+            crate::git::redact_credentials(url),
+            mirror.display()
+        ));
+        return Ok(mirror);
+    }
+
+    let store = store_dir(viewset_root);
+    std::fs::create_dir_all(&store).context("Failed to create .viewyard-store directory")?;
+
+    let credential = crate::credentials::resolve(url, preferred_auth).ok();
+    let clone_url = credential
+        .as_ref()
+        .map_or_else(|| url.to_string(), |c| c.clone_url(url));
+
+    let output = clone_mirror(&clone_url, &mirror, branch_name, strategy, &on_progress)?;
+    if output.status.success() {
+        scrub_mirror_remote_url(&mirror, url)?;
+        return Ok(mirror);
+    }
+
+    let error = crate::git::GitError::classify(&output);
+
+    // Only SSH failures are worth retrying over HTTPS - any other
+    // failure (repo not found, network down, ...) would fail the same
+    // way again regardless of transport.
+    let retried = if error == crate::git::GitError::SshAuth && credential != Some(crate::credentials::Credential::Ssh) {
+        crate::credentials::resolve(url, crate::credentials::AuthMethod::Https)
+            .ok()
+            .map(|c| c.clone_url(url))
+    } else {
+        None
+    };
+
+    if let Some(retry_url) = retried {
+        // The failed attempt may have left a partial mirror directory
+        // behind; `git clone` refuses to clone into a non-empty one.
+        if mirror.exists() {
+            std::fs::remove_dir_all(&mirror)
+                .context("Failed to remove partial mirror before retrying over HTTPS")?;
+        }
+        let retry_output = clone_mirror(&retry_url, &mirror, branch_name, strategy, &on_progress)?;
+        if retry_output.status.success() {
+            scrub_mirror_remote_url(&mirror, url)?;
+            return Ok(mirror);
+        }
+        let retry_error = crate::git::GitError::classify(&retry_output);
+        crate::error_handling::handle_clone_error(name, &retry_error)?;
+    } else {
+        crate::error_handling::handle_clone_error(name, &error)?;
+    }
+
+    Ok(mirror)
+}
+
+/// Rewrite a freshly-cloned mirror's `remote.origin.url` back to the
+/// original (tokenless) `url` it was asked to mirror.
+///
+/// [`crate::credentials::Credential::HttpsToken`] embeds a live GitHub token
+/// as HTTPS userinfo so `git clone` can authenticate, but `git clone` writes
+/// whatever URL it was given verbatim into the clone's `config` file - and
+/// since this mirror is the long-lived shared store (not a one-shot clone),
+/// that would leave the token sitting in plaintext on disk indefinitely.
+/// Called right after a successful clone, before `ensure_mirror` returns.
+fn scrub_mirror_remote_url(mirror: &Path, url: &str) -> Result<()> {
+    let output = crate::git::run_git_command(&["remote", "set-url", "origin", url], Some(mirror))
+        .context("Failed to scrub credentials from mirror's remote URL")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!(
+            "Failed to reset mirror remote URL to a tokenless form: {}",
+            stderr.trim()
+        );
+    }
+    Ok(())
+}
+
+/// Run the actual `git clone --mirror --progress <url> <mirror>` for
+/// [`ensure_mirror`], shared between the initial attempt and its
+/// SSH-failed-retry-over-HTTPS.
+fn clone_mirror(
+    url: &str,
+    mirror: &Path,
+    branch_name: &str,
+    strategy: Option<&CloneStrategy>,
+    on_progress: &Option<&dyn Fn(crate::git::GitProgress)>,
+) -> Result<std::process::Output> {
+    let mirror_str = mirror.to_string_lossy().to_string();
+    let mut args = vec![
+        "clone".to_string(),
+        "--mirror".to_string(),
+        "--progress".to_string(),
+    ];
+    if let Some(strategy) = strategy {
+        args.extend(strategy.clone_args(branch_name));
+    }
+    args.push(url.to_string());
+    args.push(mirror_str);
+
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    crate::git::run_git_command_with_progress(&arg_refs, None, |progress| {
+        if let Some(on_progress) = on_progress {
+            on_progress(progress);
+        }
+    })
+    .context("Failed to execute git clone --mirror")
+}
+
+/// Materialize a worktree for `name` at `dest_dir/name`, checked out onto
+/// `branch_name` (created off the mirror's current `HEAD` if it doesn't
+/// exist yet), sharing `mirror`'s object database rather than copying it.
+pub fn add_worktree(
+    mirror: &Path,
+    dest_dir: &Path,
+    name: &str,
+    branch_name: &str,
+) -> Result<BranchSetupOutcome> {
+    let repo_path = dest_dir.join(name);
+    let repo_path_str = repo_path.to_string_lossy().to_string();
+
+    if crate::git::exec_mode() == crate::git::ExecMode::DryRun {
+        crate::ui::print_info(&format!("would run: git worktree add {repo_path_str} {branch_name}"));
+        return Ok(BranchSetupOutcome::Created);
+    }
+
+    let check_output = crate::git::run_git_command(&["branch", "--list", branch_name], Some(mirror))
+        .context("Failed to check if branch exists in mirror")?;
+    let branch_exists = !String::from_utf8_lossy(&check_output.stdout)
+        .trim()
+        .is_empty();
+
+    let args: Vec<&str> = if branch_exists {
+        vec!["worktree", "add", &repo_path_str, branch_name]
+    } else {
+        vec!["worktree", "add", &repo_path_str, "-b", branch_name]
+    };
+
+    let output = crate::git::run_git_command(&args, Some(mirror))
+        .context("Failed to execute git worktree add")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("Failed to create worktree for '{name}': {}", stderr.trim());
+    }
+
+    Ok(if branch_exists {
+        BranchSetupOutcome::CheckedOutExisting
+    } else {
+        BranchSetupOutcome::Created
+    })
+}
+
+/// Repair a worktree's administrative files after its containing directory
+/// was moved (e.g. the atomic temp-directory rename `create_view` uses), so
+/// the mirror's worktree list points at the worktree's new path.
+pub fn repair_worktree(repo_path: &Path) -> Result<()> {
+    let output = crate::git::run_git_command(&["worktree", "repair"], Some(repo_path))
+        .context("Failed to execute git worktree repair")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!(
+            "Failed to repair worktree at '{}': {}",
+            repo_path.display(),
+            stderr.trim()
+        );
+    }
+    Ok(())
+}
+
+/// Prune stale worktree references from every mirror in `viewset_root`'s
+/// shared store, e.g. after a view directory has been removed from disk.
+pub fn prune_mirrors(viewset_root: &Path) -> Result<()> {
+    let store = store_dir(viewset_root);
+    if !store.exists() {
+        return Ok(());
+    }
+
+    for entry in std::fs::read_dir(&store).context("Failed to read .viewyard-store directory")? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let mirror = entry.path();
+        let output = crate::git::run_git_command(&["worktree", "prune"], Some(&mirror))
+            .context("Failed to execute git worktree prune")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!(
+                "Failed to prune worktrees for '{}': {}",
+                mirror.display(),
+                stderr.trim()
+            );
+        }
+    }
+
+    Ok(())
+}
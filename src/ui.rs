@@ -11,14 +11,134 @@ impl Colors {
     pub const RESET: &'static str = "\x1b[0m";
 }
 
+/// Whether [`print_colored`]/[`eprint_colored`] emit ANSI escapes at all,
+/// pinned process-wide by a `--color` CLI flag. `Auto` (the default) instead
+/// decides per call, honoring `NO_COLOR` and whether the destination stream
+/// is a real terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    /// Parse a `--color` flag value (`auto`/`always`/`never`).
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            other => anyhow::bail!("Invalid --color value '{other}' (expected auto, always, or never)"),
+        }
+    }
+}
+
+static COLOR_MODE: std::sync::OnceLock<std::sync::Mutex<ColorMode>> = std::sync::OnceLock::new();
+
+fn color_mode_cell() -> &'static std::sync::Mutex<ColorMode> {
+    COLOR_MODE.get_or_init(|| std::sync::Mutex::new(ColorMode::Auto))
+}
+
+/// Set the process-wide color mode, e.g. from a `--color` CLI flag.
+pub fn set_color_mode(mode: ColorMode) {
+    *color_mode_cell().lock().unwrap() = mode;
+}
+
+/// The current process-wide color mode.
+#[must_use]
+pub fn color_mode() -> ColorMode {
+    *color_mode_cell().lock().unwrap()
+}
+
+/// Output format for commands that can emit structured data (e.g. `status`),
+/// pinned process-wide by a `--format` CLI flag. `Text` (the default) prints
+/// the existing colored prose; `Json` instead serializes a result struct
+/// (see [`crate::models::ViewStatus`]) to stdout and suppresses the prose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Parse a `--format` flag value (`text`/`json`).
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            other => anyhow::bail!("Invalid --format value '{other}' (expected text or json)"),
+        }
+    }
+}
+
+static OUTPUT_FORMAT: std::sync::OnceLock<std::sync::Mutex<OutputFormat>> = std::sync::OnceLock::new();
+
+fn output_format_cell() -> &'static std::sync::Mutex<OutputFormat> {
+    OUTPUT_FORMAT.get_or_init(|| std::sync::Mutex::new(OutputFormat::Text))
+}
+
+/// Set the process-wide output format, e.g. from a `--format` CLI flag.
+pub fn set_output_format(format: OutputFormat) {
+    *output_format_cell().lock().unwrap() = format;
+}
+
+/// The current process-wide output format.
+#[must_use]
+pub fn output_format() -> OutputFormat {
+    *output_format_cell().lock().unwrap()
+}
+
+/// Serialize `value` as pretty JSON to stdout, for `--format json` commands.
+pub fn print_json<T: serde::Serialize>(value: &T) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+/// Whether to actually emit color, given an explicit `mode`/`no_color_env`
+/// (split out from [`should_colorize`] so tests can check the decision logic
+/// without mutating process env or the global [`COLOR_MODE`]).
+/// `Always`/`Never` are explicit overrides and ignore both `NO_COLOR` and
+/// terminal detection; `Auto` colors only when `NO_COLOR` is unset and
+/// `is_terminal` is true.
+fn should_colorize_with(mode: ColorMode, no_color_env: bool, is_terminal: bool) -> bool {
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => !no_color_env && is_terminal,
+    }
+}
+
+/// Whether to colorize output to a stream, given whether that stream
+/// (stdout or stderr - callers check their own) `is_terminal`.
+fn should_colorize(is_terminal: bool) -> bool {
+    should_colorize_with(color_mode(), std::env::var_os("NO_COLOR").is_some(), is_terminal)
+}
+
+/// Render `text` wrapped in `color`/[`Colors::RESET`] when `colorize`, or
+/// plain when not - split out from [`print_colored`]/[`eprint_colored`] so
+/// the no-escapes-when-disabled behavior is unit-testable without capturing
+/// stdout/stderr.
+fn render_colored(text: &str, color: &str, colorize: bool) -> String {
+    if colorize {
+        format!("{color}{text}{}", Colors::RESET)
+    } else {
+        text.to_string()
+    }
+}
+
 /// Print colored text to stdout
 pub fn print_colored(text: &str, color: &str) {
-    println!("{}{}{}", color, text, Colors::RESET);
+    use std::io::IsTerminal;
+    println!("{}", render_colored(text, color, should_colorize(std::io::stdout().is_terminal())));
 }
 
 /// Print colored text to stderr
 pub fn eprint_colored(text: &str, color: &str) {
-    eprintln!("{}{}{}", color, text, Colors::RESET);
+    use std::io::IsTerminal;
+    eprintln!("{}", render_colored(text, color, should_colorize(std::io::stderr().is_terminal())));
 }
 
 /// Print success message
@@ -46,6 +166,104 @@ pub fn print_header(text: &str) {
     print_colored(text, Colors::PURPLE);
 }
 
+/// Non-interactive numbered-list picker used when a real terminal isn't
+/// available (piped input, the `test-` shortcut). Accepts comma/space
+/// separated indices and, when `multi` is false, keeps only the first one.
+pub fn select_from_list(items: &[String], prompt: &str, multi: bool) -> anyhow::Result<Vec<usize>> {
+    use std::io::Write;
+
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    print_info(prompt);
+    for (i, item) in items.iter().enumerate() {
+        println!("  {}. {}", i + 1, item);
+    }
+
+    print!("Select {} (numbers, comma-separated): ", if multi { "one or more" } else { "one" });
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut indices = Vec::new();
+    for part in input.split(&[',', ' '][..]).map(str::trim).filter(|s| !s.is_empty()) {
+        let n: usize = part
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid selection: '{part}'"))?;
+        if n == 0 || n > items.len() {
+            anyhow::bail!("Selection '{n}' is out of range (1-{})", items.len());
+        }
+        indices.push(n - 1);
+        if !multi {
+            break;
+        }
+    }
+
+    Ok(indices)
+}
+
+/// Tracks aggregate clone/fetch progress across a set of repos being set up
+/// concurrently (e.g. `view create`'s per-repo worker pool), printing a
+/// per-repo transfer stage line as [`crate::git::GitProgress`] updates arrive
+/// plus a running "X/Y repos cloned" summary as each repo finishes. Shared by
+/// reference across worker threads, which [`crate::parallel::parallel_map`]'s
+/// `std::thread::scope` makes safe without an `Arc`.
+pub struct Progress {
+    total: usize,
+    completed: std::sync::atomic::AtomicUsize,
+    print_lock: std::sync::Mutex<()>,
+}
+
+impl Progress {
+    #[must_use]
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            completed: std::sync::atomic::AtomicUsize::new(0),
+            print_lock: std::sync::Mutex::new(()),
+        }
+    }
+
+    /// Report one parsed transfer update for `repo_name`. Only a stage's
+    /// start (no percent yet) and its completion (100%) are printed, rather
+    /// than every percentage point, so concurrent repos don't flood the
+    /// terminal with interleaved lines.
+    pub fn report(&self, repo_name: &str, progress: &crate::git::GitProgress) {
+        if !matches!(progress.percent, None | Some(100)) {
+            return;
+        }
+        let _guard = self.print_lock.lock().unwrap();
+        println!("  [{repo_name}] {}", progress.stage);
+    }
+
+    /// Mark one repo's clone/fetch as finished and print the updated
+    /// "X/Y repos cloned" aggregate. On a real terminal, successive
+    /// summaries overwrite the same line; off a terminal (piped output, CI),
+    /// each is printed on its own line instead.
+    pub fn repo_completed(&self, repo_name: &str) {
+        use std::io::{IsTerminal, Write};
+
+        let done = self.completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let _guard = self.print_lock.lock().unwrap();
+        let summary = format!("{repo_name} done ({done}/{} repos cloned)", self.total);
+        if std::io::stdout().is_terminal() {
+            print!("\r\x1b[K  {summary}");
+            if done == self.total {
+                println!();
+            }
+            let _ = std::io::stdout().flush();
+        } else {
+            println!("  {summary}");
+        }
+    }
+}
+
 /// Show a helpful error with suggestions
 pub fn show_error_with_help(error: &str, suggestions: &[&str]) {
     print_error(&format!("❌ {error}"));
@@ -58,3 +276,69 @@ pub fn show_error_with_help(error: &str, suggestions: &[&str]) {
         println!();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_colored_includes_escapes_when_colorize_true() {
+        let rendered = render_colored("hello", Colors::RED, true);
+        assert_eq!(rendered, format!("{}hello{}", Colors::RED, Colors::RESET));
+    }
+
+    #[test]
+    fn test_render_colored_strips_escapes_when_colorize_false() {
+        let rendered = render_colored("hello", Colors::RED, false);
+        assert_eq!(rendered, "hello");
+        assert!(!rendered.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_should_colorize_with_always_ignores_no_color_and_terminal() {
+        assert!(should_colorize_with(ColorMode::Always, true, false));
+    }
+
+    #[test]
+    fn test_should_colorize_with_never_ignores_terminal() {
+        assert!(!should_colorize_with(ColorMode::Never, false, true));
+    }
+
+    #[test]
+    fn test_should_colorize_with_auto_respects_no_color_env() {
+        assert!(!should_colorize_with(ColorMode::Auto, true, true));
+        assert!(should_colorize_with(ColorMode::Auto, false, true));
+    }
+
+    #[test]
+    fn test_should_colorize_with_auto_requires_terminal() {
+        assert!(!should_colorize_with(ColorMode::Auto, false, false));
+    }
+
+    #[test]
+    fn test_color_mode_parse_accepts_known_values_case_insensitively() {
+        assert_eq!(ColorMode::parse("auto").unwrap(), ColorMode::Auto);
+        assert_eq!(ColorMode::parse("ALWAYS").unwrap(), ColorMode::Always);
+        assert_eq!(ColorMode::parse("Never").unwrap(), ColorMode::Never);
+        assert!(ColorMode::parse("rainbow").is_err());
+    }
+
+    #[test]
+    fn test_output_format_parse_accepts_known_values_case_insensitively() {
+        assert_eq!(OutputFormat::parse("text").unwrap(), OutputFormat::Text);
+        assert_eq!(OutputFormat::parse("JSON").unwrap(), OutputFormat::Json);
+        assert!(OutputFormat::parse("yaml").is_err());
+    }
+
+    #[test]
+    fn test_no_color_env_var_disables_auto_mode_output() {
+        // Asserts the literal NO_COLOR=1 convention this request calls out,
+        // via the real env-reading `should_colorize` (not just the `_with`
+        // helper above).
+        std::env::set_var("NO_COLOR", "1");
+        let colorize = should_colorize(true);
+        std::env::remove_var("NO_COLOR");
+        assert!(!colorize);
+        assert!(!render_colored("hello", Colors::RED, colorize).contains('\x1b'));
+    }
+}
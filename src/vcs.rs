@@ -0,0 +1,328 @@
+//! VCS-agnostic repo detection and branch/status queries.
+//!
+//! Workspace operations mostly assume every repo in a view is git, via
+//! [`crate::backend::GitBackend`]. [`VcsBackend`] narrows to the read-only
+//! operations branch-synchronization checking needs - is this a repo at all,
+//! what branch/bookmark is it on, does it have uncommitted changes - so a
+//! view can mix Git repos with Mercurial ones and still be checked for a
+//! consistent branch name across all of them.
+
+use crate::models::{CloneStrategy, Repository};
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Outcome of [`VcsBackend::create_or_checkout_branch`], so callers can print
+/// "created" vs. "checked out existing" without re-deriving it themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchSetupOutcome {
+    Created,
+    CheckedOutExisting,
+}
+
+/// VCS operations needed to clone, branch-switch, and status-check a repo
+/// across a view, independent of which version control system it uses.
+pub trait VcsBackend {
+    /// Whether `path` is a repository of this backend's VCS.
+    fn is_repo(&self, path: &Path) -> bool;
+    /// The current branch (or, for Mercurial, bookmark/branch) name.
+    fn current_branch(&self, path: &Path) -> Result<String>;
+    /// Whether the working tree has uncommitted changes.
+    fn working_tree_status(&self, path: &Path) -> Result<bool>;
+    /// Clone `url` into `dest_dir/name`.
+    fn clone_repo(&self, url: &str, dest_dir: &Path, name: &str) -> Result<()>;
+    /// Switch `repo_path` onto `branch_name`, creating it first if it doesn't
+    /// exist yet.
+    fn create_or_checkout_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<BranchSetupOutcome>;
+    /// Materialize `dest_dir/name` on `branch_name`, sharing object storage
+    /// under `store_root` when this backend supports it. Backends without a
+    /// cheaper mode fall back to a plain [`VcsBackend::clone_repo`] followed
+    /// by [`VcsBackend::create_or_checkout_branch`], ignoring `clone_strategy`
+    /// since they have no shallow/partial clone equivalent.
+    fn setup_worktree(
+        &self,
+        url: &str,
+        _store_root: &Path,
+        dest_dir: &Path,
+        name: &str,
+        branch_name: &str,
+        _clone_strategy: Option<&CloneStrategy>,
+        _on_progress: Option<&dyn Fn(crate::git::GitProgress)>,
+        _preferred_auth: crate::credentials::AuthMethod,
+    ) -> Result<BranchSetupOutcome> {
+        self.clone_repo(url, dest_dir, name)?;
+        self.create_or_checkout_branch(&dest_dir.join(name), branch_name)
+    }
+    /// Check that this backend's CLI is installed and usable.
+    fn check_availability(&self) -> Result<()>;
+}
+
+/// Git repos, via the existing [`crate::git`] subprocess helpers.
+#[derive(Debug, Default)]
+pub struct GitVcsBackend;
+
+impl VcsBackend for GitVcsBackend {
+    fn is_repo(&self, path: &Path) -> bool {
+        crate::git::is_git_repo(path)
+    }
+
+    fn current_branch(&self, path: &Path) -> Result<String> {
+        crate::git::get_current_branch(path)
+    }
+
+    fn working_tree_status(&self, path: &Path) -> Result<bool> {
+        crate::git::has_uncommitted_changes(path)
+    }
+
+    fn clone_repo(&self, url: &str, dest_dir: &Path, name: &str) -> Result<()> {
+        // Delegate to `crate::git`'s own clone rather than re-shelling out
+        // here, so this fallback path (only reached if something ever stops
+        // overriding `setup_worktree` below) still gets dry-run, timeout, and
+        // askpass credential handling instead of silently regressing them.
+        crate::git::clone_repository(url, &dest_dir.join(name))
+    }
+
+    fn create_or_checkout_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<BranchSetupOutcome> {
+        let check_output = crate::git::run_git_command(&["branch", "--list", branch_name], Some(repo_path))
+            .context("Failed to check if branch exists")?;
+
+        let branch_exists = !String::from_utf8_lossy(&check_output.stdout)
+            .trim()
+            .is_empty();
+
+        crate::git::checkout_branch(repo_path, branch_name)?;
+
+        Ok(if branch_exists {
+            BranchSetupOutcome::CheckedOutExisting
+        } else {
+            BranchSetupOutcome::Created
+        })
+    }
+
+    fn setup_worktree(
+        &self,
+        url: &str,
+        store_root: &Path,
+        dest_dir: &Path,
+        name: &str,
+        branch_name: &str,
+        clone_strategy: Option<&CloneStrategy>,
+        on_progress: Option<&dyn Fn(crate::git::GitProgress)>,
+        preferred_auth: crate::credentials::AuthMethod,
+    ) -> Result<BranchSetupOutcome> {
+        let mirror = crate::store::ensure_mirror(
+            url,
+            store_root,
+            name,
+            branch_name,
+            clone_strategy,
+            on_progress,
+            preferred_auth,
+        )?;
+        let outcome = crate::store::add_worktree(&mirror, dest_dir, name, branch_name)?;
+        crate::git::init_submodules_if_present(
+            &dest_dir.join(name),
+            clone_strategy.and_then(|s| s.depth),
+        )?;
+        Ok(outcome)
+    }
+
+    fn check_availability(&self) -> Result<()> {
+        crate::git::check_git_availability()
+    }
+}
+
+/// Mercurial repos, shelling out to `hg`.
+#[derive(Debug, Default)]
+pub struct MercurialVcsBackend;
+
+impl VcsBackend for MercurialVcsBackend {
+    fn is_repo(&self, path: &Path) -> bool {
+        path.join(".hg").is_dir()
+    }
+
+    fn current_branch(&self, path: &Path) -> Result<String> {
+        let output = crate::git::create_command("hg")
+            .args(["branch"])
+            .current_dir(path)
+            .output()
+            .context("Failed to execute 'hg branch'")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "'hg branch' failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn working_tree_status(&self, path: &Path) -> Result<bool> {
+        let output = crate::git::create_command("hg")
+            .args(["status"])
+            .current_dir(path)
+            .output()
+            .context("Failed to execute 'hg status'")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "'hg status' failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(!String::from_utf8_lossy(&output.stdout).trim().is_empty())
+    }
+
+    fn clone_repo(&self, url: &str, dest_dir: &Path, name: &str) -> Result<()> {
+        let output = crate::git::create_command("hg")
+            .args(["clone", url, name])
+            .current_dir(dest_dir)
+            .output()
+            .context("Failed to execute hg clone")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "'hg clone' failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(())
+    }
+
+    fn create_or_checkout_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<BranchSetupOutcome> {
+        let list_output = crate::git::create_command("hg")
+            .args(["branches"])
+            .current_dir(repo_path)
+            .output()
+            .context("Failed to execute 'hg branches'")?;
+        let branch_exists = String::from_utf8_lossy(&list_output.stdout)
+            .lines()
+            .any(|line| line.split_whitespace().next() == Some(branch_name));
+
+        let output = crate::git::create_command("hg")
+            .args(["branch", branch_name])
+            .current_dir(repo_path)
+            .output()
+            .context("Failed to execute 'hg branch'")?;
+
+        if !output.status.success() {
+            anyhow::bail!(
+                "'hg branch' failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(if branch_exists {
+            BranchSetupOutcome::CheckedOutExisting
+        } else {
+            BranchSetupOutcome::Created
+        })
+    }
+
+    fn check_availability(&self) -> Result<()> {
+        let output = crate::git::create_command("hg")
+            .args(["--version"])
+            .output()
+            .context("Mercurial is not installed or not available in PATH")?;
+
+        if !output.status.success() {
+            anyhow::bail!("Mercurial is not installed or not available in PATH");
+        }
+
+        Ok(())
+    }
+}
+
+/// Which version control system a repository uses, resolved from
+/// [`Repository::backend`] (defaulting to `Git` when unset, which matches
+/// viewyard's historical git-only behavior).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Backend {
+    Git,
+    Mercurial,
+    Unknown(String),
+}
+
+impl Backend {
+    /// Resolve the backend a repo declares, defaulting to `Git`.
+    #[must_use]
+    pub fn from_repo(repo: &Repository) -> Self {
+        match repo.backend.as_deref() {
+            None | Some("git") => Backend::Git,
+            Some("hg" | "mercurial") => Backend::Mercurial,
+            Some(other) => Backend::Unknown(other.to_string()),
+        }
+    }
+
+    /// A short label for error messages and availability reports.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            Backend::Git => "git",
+            Backend::Mercurial => "mercurial",
+            Backend::Unknown(name) => name,
+        }
+    }
+
+    /// Resolve to the [`VcsBackend`] implementation for this backend kind, or
+    /// an error for an `Unknown` backend name.
+    pub fn resolve(&self) -> Result<Box<dyn VcsBackend>> {
+        match self {
+            Backend::Git => Ok(Box::new(GitVcsBackend)),
+            Backend::Mercurial => Ok(Box::new(MercurialVcsBackend)),
+            Backend::Unknown(name) => anyhow::bail!("Unsupported VCS backend '{name}'"),
+        }
+    }
+}
+
+/// Check that every distinct backend declared across `repos` has its CLI
+/// available, generalizing [`crate::git::check_git_availability`] to
+/// viewsets that mix Git and Mercurial repos.
+pub fn check_backends_available(repos: &[Repository]) -> Result<()> {
+    let mut checked = std::collections::HashSet::new();
+
+    for repo in repos {
+        let backend = Backend::from_repo(repo);
+        if !checked.insert(backend.label().to_string()) {
+            continue;
+        }
+        backend
+            .resolve()?
+            .check_availability()
+            .with_context(|| format!("'{}' backend is not available", backend.label()))?;
+    }
+
+    Ok(())
+}
+
+/// Probe `path` for a `.git` entry (a directory, or the file a worktree
+/// has) or a `.hg` directory and return the matching
+/// backend. Neither present is an error rather than a silent default, so a
+/// repo in an unrecognized (or missing) VCS surfaces clearly instead of
+/// being treated as a plain absent git repo.
+pub fn resolve_vcs_backend(path: &Path) -> Result<Box<dyn VcsBackend>> {
+    if path.join(".git").exists() {
+        return Ok(Box::new(GitVcsBackend));
+    }
+    if path.join(".hg").is_dir() {
+        return Ok(Box::new(MercurialVcsBackend));
+    }
+    anyhow::bail!(
+        "'{}' is not a recognized Git or Mercurial repository",
+        path.display()
+    )
+}
@@ -0,0 +1,149 @@
+//! Integration tests that exercise real clone traffic against
+//! disposable git servers running in Docker.
+//!
+//! These start an SSH server container and an HTTP server container
+//! (git-http-backend behind Apache) from the `viewyard-test-sshd` and
+//! `viewyard-test-apache` images, which the tests do not build, and run
+//! `viewyard view create` against the repo each one serves, checking only
+//! that the command succeeds. They only run when Docker is available and
+//! `VIEWYARD_DOCKER_TESTS=1` is set, mirroring how cargo's own test-support
+//! crate gates its container-backed tests.
+
+use assert_cmd::Command as AssertCommand;
+use std::process::Command;
+use tempfile::TempDir;
+
+/// Returns true when the container-backed suite should run: Docker must be
+/// reachable and the caller must have opted in via an env var, since these
+/// tests are slow and unavailable in most CI sandboxes.
+fn docker_tests_enabled() -> bool {
+    if std::env::var("VIEWYARD_DOCKER_TESTS").as_deref() != Ok("1") {
+        return false;
+    }
+
+    Command::new("docker")
+        .args(["info"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// A disposable git server running in a Docker container, reachable at a
+/// clonable URL. Dropping it stops and removes the container.
+struct GitServerContainer {
+    container_id: String,
+    pub url: String,
+}
+
+impl GitServerContainer {
+    /// Start an SSH-based git server container; its URL addresses
+    /// `/srv/repo.git` as root.
+    fn start_ssh(fixture_dir: &std::path::Path) -> Self {
+        Self::start("viewyard-test-sshd", fixture_dir, 2222, |port| {
+            format!("ssh://root@127.0.0.1:{port}/srv/repo.git")
+        })
+    }
+
+    /// Start an HTTP git server container (git-http-backend behind Apache);
+    /// its URL addresses `repo.git` over `https://`.
+    fn start_http(fixture_dir: &std::path::Path) -> Self {
+        Self::start("viewyard-test-apache", fixture_dir, 8443, |port| {
+            format!("https://127.0.0.1:{port}/repo.git")
+        })
+    }
+
+    fn start(
+        image: &str,
+        fixture_dir: &std::path::Path,
+        port: u16,
+        url_for_port: impl FnOnce(u16) -> String,
+    ) -> Self {
+        let output = Command::new("docker")
+            .args([
+                "run",
+                "-d",
+                "--rm",
+                "-p",
+                &format!("{port}:{port}"),
+                "-v",
+                &format!("{}:/fixtures:ro", fixture_dir.display()),
+                image,
+            ])
+            .output()
+            .expect("failed to start git server container");
+
+        assert!(
+            output.status.success(),
+            "docker run failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let container_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        GitServerContainer {
+            container_id,
+            url: url_for_port(port),
+        }
+    }
+}
+
+impl Drop for GitServerContainer {
+    fn drop(&mut self) {
+        let _ = Command::new("docker")
+            .args(["rm", "-f", &self.container_id])
+            .output();
+    }
+}
+
+#[test]
+fn test_view_create_over_ssh() {
+    if !docker_tests_enabled() {
+        eprintln!("skipping: set VIEWYARD_DOCKER_TESTS=1 with Docker available to run this test");
+        return;
+    }
+
+    let fixture_dir = TempDir::new().unwrap();
+    let server = GitServerContainer::start_ssh(fixture_dir.path());
+
+    let temp_dir = TempDir::new().unwrap();
+    let repos_json = format!(
+        r#"[{{"name": "repo", "url": "{}", "is_private": false, "source": "Test"}}]"#,
+        server.url
+    );
+    std::fs::write(temp_dir.path().join(".viewyard-repos.json"), repos_json).unwrap();
+
+    let mut cmd = AssertCommand::cargo_bin("viewyard").unwrap();
+    cmd.arg("view")
+        .arg("create")
+        .arg("smoke-test")
+        .current_dir(temp_dir.path())
+        .env("HOME", temp_dir.path())
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_view_create_over_https() {
+    if !docker_tests_enabled() {
+        eprintln!("skipping: set VIEWYARD_DOCKER_TESTS=1 with Docker available to run this test");
+        return;
+    }
+
+    let fixture_dir = TempDir::new().unwrap();
+    let server = GitServerContainer::start_http(fixture_dir.path());
+
+    let temp_dir = TempDir::new().unwrap();
+    let repos_json = format!(
+        r#"[{{"name": "repo", "url": "{}", "is_private": false, "source": "Test"}}]"#,
+        server.url
+    );
+    std::fs::write(temp_dir.path().join(".viewyard-repos.json"), repos_json).unwrap();
+
+    let mut cmd = AssertCommand::cargo_bin("viewyard").unwrap();
+    cmd.arg("view")
+        .arg("create")
+        .arg("smoke-test")
+        .current_dir(temp_dir.path())
+        .env("HOME", temp_dir.path())
+        .assert()
+        .success();
+}
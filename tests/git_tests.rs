@@ -178,6 +178,44 @@ fn test_extract_account_from_source() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_parse_remote() -> Result<()> {
+    use viewyard::git::{parse_remote, Forge};
+
+    let https = parse_remote("https://github.com/dheater/viewyard.git")?;
+    assert_eq!(https.host, "github.com");
+    assert_eq!(https.owner, "dheater");
+    assert_eq!(https.repo, "viewyard");
+    assert_eq!(https.forge, Forge::GitHub);
+
+    let scp = parse_remote("git@gitlab.com:group/dheater.git")?;
+    assert_eq!(scp.host, "gitlab.com");
+    assert_eq!(scp.owner, "group");
+    assert_eq!(scp.repo, "dheater");
+    assert_eq!(scp.forge, Forge::GitLab);
+
+    // Nested GitLab subgroups: owner is everything before the final segment
+    let nested = parse_remote("https://gitlab.com/group/subgroup/repo.git")?;
+    assert_eq!(nested.owner, "group/subgroup");
+    assert_eq!(nested.repo, "repo");
+
+    let ssh_with_port = parse_remote("ssh://git@example.org:2222/owner/repo.git")?;
+    assert_eq!(ssh_with_port.host, "example.org");
+    assert_eq!(ssh_with_port.owner, "owner");
+    assert_eq!(ssh_with_port.repo, "repo");
+    assert_eq!(ssh_with_port.forge, Forge::Forgejo);
+
+    // `~user` personal-namespace prefix used by some Gitea/Forgejo instances
+    let tilde = parse_remote("https://git.example.com/~owner/repo.git")?;
+    assert_eq!(tilde.owner, "owner");
+    assert_eq!(tilde.repo, "repo");
+
+    assert!(parse_remote("not-a-url").is_err());
+    assert!(parse_remote("https://github.com/justowner").is_err());
+
+    Ok(())
+}
+
 #[test]
 fn test_validate_repository_directory() -> Result<()> {
     use viewyard::git::validate_repository_directory;
@@ -380,6 +418,32 @@ fn test_global_config_never_modified() -> Result<()> {
     set_git_config("user.name", "anotheruser", repo_path)?;
     set_git_config("user.email", "test@example.com", repo_path)?;
 
+    // Credential env vars must be scoped to the child `Command` alone, never
+    // written to config (local or global) or leaked into our own process env.
+    let mut throwaway = std::process::Command::new("git");
+    git::configure_credential_env(&mut throwaway)?;
+    assert!(
+        std::env::var_os("GIT_ASKPASS").is_none(),
+        "GIT_ASKPASS leaked into the current process environment"
+    );
+
+    let local_askpass = std::process::Command::new("git")
+        .args(["config", "--local", "core.askPass"])
+        .current_dir(repo_path)
+        .output()?;
+    assert!(
+        !local_askpass.status.success(),
+        "configure_credential_env must not write core.askPass to local config"
+    );
+
+    let global_askpass = std::process::Command::new("git")
+        .args(["config", "--global", "core.askPass"])
+        .output()?;
+    assert!(
+        !global_askpass.status.success(),
+        "configure_credential_env must not write core.askPass to global config"
+    );
+
     // Verify global git config is unchanged
     let final_global_name = std::process::Command::new("git")
         .args(["config", "--global", "user.name"])
@@ -427,3 +491,114 @@ fn test_global_config_never_modified() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_status_summary_clean_repo() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path();
+
+    Command::new("git").args(["init"]).current_dir(repo_path).output()?;
+    Command::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(repo_path)
+        .output()?;
+    Command::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(repo_path)
+        .output()?;
+    fs::write(repo_path.join("README.md"), "# Test")?;
+    Command::new("git").args(["add", "."]).current_dir(repo_path).output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(repo_path)
+        .output()?;
+
+    let summary = git::status_summary(repo_path)?;
+    assert!(summary.is_clean());
+    assert_eq!(summary.render(), "✓");
+
+    Ok(())
+}
+
+#[test]
+fn test_status_summary_detects_staged_and_unstaged_and_untracked() -> Result<()> {
+    let temp_dir = TempDir::new()?;
+    let repo_path = temp_dir.path();
+
+    Command::new("git").args(["init"]).current_dir(repo_path).output()?;
+    Command::new("git")
+        .args(["config", "user.name", "Test User"])
+        .current_dir(repo_path)
+        .output()?;
+    Command::new("git")
+        .args(["config", "user.email", "test@example.com"])
+        .current_dir(repo_path)
+        .output()?;
+    fs::write(repo_path.join("tracked.txt"), "v1")?;
+    Command::new("git").args(["add", "."]).current_dir(repo_path).output()?;
+    Command::new("git")
+        .args(["commit", "-m", "Initial commit"])
+        .current_dir(repo_path)
+        .output()?;
+
+    // Stage a new file (staged addition) and leave an untracked file and an
+    // unstaged modification to the tracked one.
+    fs::write(repo_path.join("staged.txt"), "new")?;
+    Command::new("git")
+        .args(["add", "staged.txt"])
+        .current_dir(repo_path)
+        .output()?;
+    fs::write(repo_path.join("tracked.txt"), "v2")?;
+    fs::write(repo_path.join("untracked.txt"), "new")?;
+
+    let summary = git::status_summary(repo_path)?;
+    assert!(!summary.is_clean());
+    assert!(summary.staged_added);
+    assert!(summary.unstaged_modified);
+    assert!(summary.untracked);
+    assert!(!summary.staged_deleted);
+    assert!(!summary.staged_renamed);
+
+    let rendered = summary.render();
+    assert!(rendered.contains('+'));
+    assert!(rendered.contains('!'));
+    assert!(rendered.contains('?'));
+
+    Ok(())
+}
+
+#[test]
+fn test_view_status_summaries_aggregates_submodule_directories() -> Result<()> {
+    let view_dir = TempDir::new()?;
+    let view_path = view_dir.path();
+
+    for name in ["repo-a", "repo-b"] {
+        let repo_path = view_path.join(name);
+        fs::create_dir_all(&repo_path)?;
+        Command::new("git").args(["init"]).current_dir(&repo_path).output()?;
+        Command::new("git")
+            .args(["config", "user.name", "Test User"])
+            .current_dir(&repo_path)
+            .output()?;
+        Command::new("git")
+            .args(["config", "user.email", "test@example.com"])
+            .current_dir(&repo_path)
+            .output()?;
+        fs::write(repo_path.join("README.md"), "# Test")?;
+        Command::new("git").args(["add", "."]).current_dir(&repo_path).output()?;
+        Command::new("git")
+            .args(["commit", "-m", "Initial commit"])
+            .current_dir(&repo_path)
+            .output()?;
+    }
+    fs::write(view_path.join("repo-b").join("dirty.txt"), "untracked")?;
+
+    let summaries = git::view_status_summaries(view_path)?;
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0].0, "repo-a");
+    assert!(summaries[0].1.is_clean());
+    assert_eq!(summaries[1].0, "repo-b");
+    assert!(summaries[1].1.untracked);
+
+    Ok(())
+}
@@ -188,12 +188,14 @@ viewsets:
     cmd.arg("view")
         .arg("validate")
         .env("HOME", temp_dir.path());
-    
-    // Should still validate the YAML structure even if URLs are invalid
-    // The actual git operations will fail later, but config parsing should work
+
+    // An unparseable repository URL should fail validation with a diagnostic
+    // naming the viewset, repo, and offending URL, rather than being waved
+    // through with a blanket "Configuration is valid".
     cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Configuration is valid"));
+        .failure()
+        .stderr(predicate::str::contains("invalid-repo"))
+        .stderr(predicate::str::contains("not-a-valid-git-url"));
 }
 
 #[test]
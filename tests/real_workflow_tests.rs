@@ -180,6 +180,42 @@ fn test_hierarchical_view_detection() {
         .stdout(predicates::str::contains("View: test-view"));
 }
 
+#[test]
+fn test_status_format_json_emits_only_valid_json_on_stdout() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let viewset_dir = temp_dir.path().join("test-viewset");
+    let view_dir = viewset_dir.join("test-view");
+    fs::create_dir_all(&view_dir).unwrap();
+
+    let repos_json = r#"[
+        {
+            "name": "repo1",
+            "url": "git@github.com:user/repo1.git",
+            "is_private": false,
+            "source": "GitHub (user)"
+        }
+    ]"#;
+    fs::write(viewset_dir.join(".viewyard-repos.json"), repos_json).unwrap();
+
+    let repo1_dir = view_dir.join("repo1");
+    fs::create_dir_all(&repo1_dir).unwrap();
+    fs::create_dir_all(repo1_dir.join(".git")).unwrap();
+
+    let mut cmd = Command::cargo_bin("viewyard").unwrap();
+    cmd.arg("--format").arg("json").arg("status").current_dir(&view_dir);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+
+    // Every byte of stdout must parse as one JSON value - no header, no
+    // "Viewset: ..." prose mixed in ahead of it.
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&output).expect("stdout must be valid JSON with --format json");
+    assert_eq!(parsed["viewset"], "test-viewset");
+    assert_eq!(parsed["view"], "test-view");
+    assert!(parsed["repos"].is_array());
+}
+
 #[test]
 fn test_directory_without_git_repos_fails() {
     let temp_dir = TempDir::new().unwrap();
@@ -267,6 +303,38 @@ fn test_view_create_with_custom_directory_name() {
     );
 }
 
+#[test]
+fn test_dry_run_view_create_leaves_filesystem_untouched() {
+    let temp_dir = TempDir::new().unwrap();
+    let viewset_dir = temp_dir.path().join("dry-run-viewset");
+    fs::create_dir_all(&viewset_dir).unwrap();
+
+    let git_setup = GitRepoSetup::new();
+    create_viewyard_config(&viewset_dir, "upstream-repo", git_setup.remote_url(), None);
+
+    let mut cmd = Command::cargo_bin("viewyard").unwrap();
+    cmd.arg("--dry-run")
+        .arg("view")
+        .arg("create")
+        .arg("feature-branch")
+        .current_dir(&viewset_dir);
+
+    cmd.assert().success();
+
+    assert!(
+        !viewset_dir.join("feature-branch").exists(),
+        "dry-run should not create the view directory"
+    );
+    assert!(
+        !viewset_dir.join("feature-branch.tmp").exists(),
+        "dry-run should not leave a temporary view directory behind"
+    );
+    assert!(
+        !viewset_dir.join(".viewyard-store").exists(),
+        "dry-run should not clone into the shared mirror store"
+    );
+}
+
 #[test]
 fn test_view_create_sets_upstream_for_existing_branch() {
     let temp_dir = TempDir::new().unwrap();
@@ -323,6 +391,37 @@ fn test_view_create_sets_upstream_for_existing_branch() {
     );
 }
 
+#[test]
+fn test_second_view_of_same_repo_reuses_mirror_without_fetching() {
+    let temp_dir = TempDir::new().unwrap();
+    let viewset_dir = temp_dir.path().join("shared-mirror-viewset");
+    fs::create_dir_all(&viewset_dir).unwrap();
+
+    let git_setup = GitRepoSetup::new();
+    create_viewyard_config(&viewset_dir, "shared-repo", git_setup.remote_url(), None);
+
+    // First view clones the repo, populating its shared mirror under
+    // .viewyard-store.
+    let mut cmd = Command::cargo_bin("viewyard").unwrap();
+    cmd.arg("view").arg("create").arg("view-one").current_dir(&viewset_dir);
+    cmd.assert().success();
+
+    // A commit lands upstream after the mirror was populated.
+    git_setup.add_upstream_commits();
+
+    // A second view of the same repo should be materialized entirely from
+    // the existing mirror, with no fetch back to the (now ahead) upstream.
+    let mut cmd = Command::cargo_bin("viewyard").unwrap();
+    cmd.arg("view").arg("create").arg("view-two").current_dir(&viewset_dir);
+    cmd.assert().success();
+
+    let view_two_repo = viewset_dir.join("view-two").join("shared-repo");
+    assert!(
+        !view_two_repo.join("upstream.txt").exists(),
+        "second view should not see the post-mirror upstream commit - ensure_mirror should not have fetched"
+    );
+}
+
 #[test]
 fn test_reproduce_upstream_tracking_bug() {
     // This test specifically reproduces the scenario where a branch is created